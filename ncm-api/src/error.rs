@@ -0,0 +1,80 @@
+use serde_json::Value;
+use std::fmt;
+
+pub type NcmResult<T> = Result<T, NcmError>;
+
+/// `NcmClient` 各方法返回的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NcmError {
+    /// 网络错误（无法连接 api 、请求超时等）
+    Network(String),
+    /// api 返回了非 200 的状态码
+    ApiCode { code: i64, message: String },
+    /// 需要登录
+    LoginRequired,
+    /// 请求过于频繁
+    RateLimited,
+    /// 响应格式不符合预期（非 json 、字段缺失或类型错误）
+    MalformedResponse(String),
+    /// 本地文件（cookie 、缓存等）读写错误
+    Io(String),
+}
+
+impl NcmError {
+    pub fn malformed(reason: impl fmt::Display) -> Self {
+        NcmError::MalformedResponse(reason.to_string())
+    }
+}
+
+impl fmt::Display for NcmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NcmError::Network(reason) => write!(f, "network error: {}", reason),
+            NcmError::ApiCode { code, message } => write!(f, "api error, code {}: {}", code, message),
+            NcmError::LoginRequired => write!(f, "login required"),
+            NcmError::RateLimited => write!(f, "rate limited, please try again later"),
+            NcmError::MalformedResponse(reason) => write!(f, "malformed response: {}", reason),
+            NcmError::Io(reason) => write!(f, "io error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for NcmError {}
+
+impl From<reqwest::Error> for NcmError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_decode() {
+            NcmError::MalformedResponse(err.to_string())
+        } else {
+            NcmError::Network(err.to_string())
+        }
+    }
+}
+
+impl From<serde_json::Error> for NcmError {
+    fn from(err: serde_json::Error) -> Self {
+        NcmError::MalformedResponse(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for NcmError {
+    fn from(err: std::io::Error) -> Self {
+        NcmError::Io(err.to_string())
+    }
+}
+
+/// 检查响应中的状态码，非 200 时转换为对应的错误
+pub(crate) fn check_response_code(v: &Value) -> NcmResult<()> {
+    match v["code"].as_i64() {
+        Some(200) => Ok(()),
+        // 未登录
+        Some(301) => Err(NcmError::LoginRequired),
+        // 操作频繁 / 网络拥挤（风控）
+        Some(405 | 429 | -460) => Err(NcmError::RateLimited),
+        Some(code) => Err(NcmError::ApiCode {
+            code,
+            message: v["message"].as_str().or(v["msg"].as_str()).unwrap_or("").to_string(),
+        }),
+        None => Err(NcmError::malformed("response without code")),
+    }
+}
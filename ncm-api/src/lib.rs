@@ -1,27 +1,70 @@
+mod error;
 pub mod model;
+mod pages;
+mod personal_fm;
 mod responses;
 mod settings;
-
-use crate::model::{Account, FromJson, LyricLine, Lyrics, Song, Songlist};
+mod song_urls;
+mod songs_availability;
+
+pub use error::{NcmError, NcmResult};
+pub use pages::{SonglistSongsLoader, UserSonglistsPages};
+pub use personal_fm::PersonalFmLoader;
+pub use song_urls::SongUrlsLoader;
+pub use songs_availability::SongsAvailabilityLoader;
+
+use crate::model::{
+    Account, Album, Artist, Comment, DjRadio, LyricLine, Lyrics, Program, Quality, RankPeriod, RankedSong, SearchItems, SearchResult, SearchType, Song, SongUrl, Songlist, SonglistSource, UserLevel, UserProfile,
+};
+use crate::responses::album::*;
+use crate::responses::artist::*;
+use crate::responses::comment::*;
 use crate::responses::login::*;
+use crate::responses::podcast::*;
+use crate::responses::recommend::*;
+use crate::responses::search::*;
+use crate::responses::song::*;
+use crate::responses::songlist::*;
+use crate::responses::user::*;
+use crate::responses::{parse_response, CodeResponse};
 use crate::settings::Settings;
-use anyhow::{anyhow, Result};
-use chrono::Utc;
+use chrono::{Local, Utc};
 use log::{debug, error};
 use regex::Regex;
 use reqwest::{Client, ClientBuilder};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::process;
 
+/// 每页获取的用户歌单数量
+const USER_SONGLISTS_PAGE_LIMIT: usize = 100;
+
+/// 每页获取的订阅电台数量
+const SUBSCRIBED_DJRADIOS_PAGE_LIMIT: usize = 100;
+
+/// 排行榜及其歌曲的缓存时长
+const TOPLIST_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// 每日推荐歌曲组成的虚拟歌单的 id （不与真实歌单冲突）
+pub const DAILY_SONGLIST_ID: u64 = 0;
+
+/// 最近播放歌曲组成的虚拟歌单的 id （不与真实歌单冲突）
+pub const RECENT_SONGLIST_ID: u64 = 1;
+
+/// 获取的最近播放歌曲数量（接口上限为 300）
+const RECENT_SONGS_LIMIT: usize = 300;
+
 pub struct NcmClient {
     api_program_path: PathBuf,
     cookie_path: PathBuf,
     lyrics_path: PathBuf,
     settings_path: PathBuf,
+    settings_override_path: Option<PathBuf>,
 
     api_child_process: Option<process::Child>,
     http_client: Client,
@@ -30,21 +73,41 @@ pub struct NcmClient {
     settings: Settings,
 
     login_account: Option<Account>,
+    liked_song_ids: HashSet<u64>,
+    /// 喜欢的歌曲每次更新后递增，界面据此刷新
+    liked_songs_revision: usize,
+
+    /// 本次运行期间的音质覆盖（优先于设置中的首选音质）
+    quality_override: Option<Quality>,
+    /// 单曲的音质覆盖（优先于 quality_override）
+    song_quality_overrides: HashMap<u64, Quality>,
+
+    /// 排行榜缓存（获取时间, 排行榜），超过 TOPLIST_CACHE_TTL 后重新获取
+    toplists_cache: Option<(Instant, Vec<Songlist>)>,
+    /// 排行榜歌曲缓存，排行榜 id -> (获取时间, 歌曲)
+    toplist_songs_cache: HashMap<u64, (Instant, Vec<Song>)>,
 }
 
 impl NcmClient {
-    pub fn new(api_program_path: PathBuf, cookie_path: PathBuf, lyrics_path: PathBuf, settings_path: PathBuf) -> Self {
+    pub fn new(api_program_path: PathBuf, cookie_path: PathBuf, lyrics_path: PathBuf, settings_path: PathBuf, settings_override_path: Option<PathBuf>) -> Self {
         Self {
             api_program_path,
             cookie_path,
             lyrics_path,
             settings_path,
+            settings_override_path,
             api_child_process: None,
             api_url: String::new(),
             http_client: ClientBuilder::new().no_proxy().build().expect("failed to build HTTP client"),
             cookie: String::new(),
             settings: Settings::default(),
             login_account: None,
+            liked_song_ids: HashSet::new(),
+            liked_songs_revision: 0,
+            quality_override: None,
+            song_quality_overrides: HashMap::new(),
+            toplists_cache: None,
+            toplist_songs_cache: HashMap::new(),
         }
     }
 
@@ -54,6 +117,38 @@ impl NcmClient {
 
         // 更新（应对本地无设置文件或Settings数据结构更新的情况）
         self.store_settings();
+
+        // 叠加账号配置的设置覆盖（只在内存中生效，不写回设置文件）
+        self.apply_settings_override();
+    }
+
+    /// 切换账号配置：更换 cookie 、歌词缓存和设置覆盖的路径，清除当前登录状态后尝试 cookie 登录
+    ///
+    /// api 相关设置发生变化时会重新检查 api
+    pub async fn switch_profile(&mut self, cookie_path: PathBuf, lyrics_path: PathBuf, settings_override_path: Option<PathBuf>) -> NcmResult<bool> {
+        let (old_use_remote_api, old_remote_api_url) = (self.settings.use_remote_api, self.settings.remote_api_url.clone());
+
+        self.cookie_path = cookie_path;
+        self.lyrics_path = lyrics_path;
+        self.settings_override_path = settings_override_path;
+
+        self.cookie = String::new();
+        self.login_account = None;
+        self.liked_song_ids = HashSet::new();
+        self.liked_songs_revision += 1;
+
+        self.init();
+
+        if self.settings.use_remote_api != old_use_remote_api || self.settings.remote_api_url != old_remote_api_url {
+            self.exit_client().await?;
+            self.api_child_process = None;
+
+            if !self.check_api().await {
+                return Err(NcmError::Network(String::from("failed to connect to api")));
+            }
+        }
+
+        self.try_cookie_login().await
     }
 
     /// 读取设置（读不到则返回默认设置）
@@ -79,6 +174,40 @@ impl NcmClient {
         settings
     }
 
+    /// 读取设置覆盖文件，覆盖其中出现的设置项
+    fn apply_settings_override(&mut self) {
+        let settings_override_path = match self.settings_override_path.as_ref() {
+            Some(settings_override_path) => settings_override_path,
+            None => return,
+        };
+
+        let v_override: Value = match fs::read_to_string(settings_override_path) {
+            Ok(override_json) => match serde_json::from_str(&override_json) {
+                Ok(v) => v,
+                Err(err) => {
+                    error!("failed to parse settings override at {:?}: {:?}", settings_override_path, err);
+                    return;
+                },
+            },
+            // 该账号配置没有设置覆盖
+            Err(_) => return,
+        };
+
+        if let (Ok(mut v_settings), Some(override_map)) = (serde_json::to_value(&self.settings), v_override.as_object()) {
+            for (key, value) in override_map {
+                v_settings[key] = value.clone();
+            }
+
+            match serde_json::from_value(v_settings) {
+                Ok(settings) => {
+                    self.settings = settings;
+                    debug!("settings after override: {:?}", self.settings);
+                },
+                Err(err) => error!("failed to apply settings override: {:?}", err),
+            }
+        }
+    }
+
     /// 保存设置
     pub fn store_settings(&mut self) {
         match serde_json::to_string_pretty(&self.settings) {
@@ -169,7 +298,7 @@ impl NcmClient {
     }
 
     /// 退出客户端时，终止 api 子进程
-    pub async fn exit_client(&mut self) -> Result<()> {
+    pub async fn exit_client(&mut self) -> NcmResult<()> {
         match self.api_child_process.as_mut() {
             Some(api_child_process) => {
                 api_child_process.kill().await?;
@@ -207,7 +336,7 @@ impl NcmClient {
     }
 
     /// 尝试从本地读取 cookie 登录
-    pub async fn try_cookie_login(&mut self) -> Result<bool> {
+    pub async fn try_cookie_login(&mut self) -> NcmResult<bool> {
         self.read_cookie();
         if self.cookie.is_empty() {
             return Ok(false);
@@ -222,7 +351,7 @@ impl NcmClient {
     }
 
     /// 获取登录二维码 (uni_key, url)
-    pub async fn get_login_qr(&self) -> Result<(String, String)> {
+    pub async fn get_login_qr(&self) -> NcmResult<(String, String)> {
         let key_response = self
             .http_client
             .get(format!("{}/login/qr/key?timestamp={}", &self.api_url, Utc::now().timestamp()))
@@ -246,15 +375,15 @@ impl NcmClient {
                 debug!("get login qr key & url: {}, {}", uni_key, create_response.data.qrurl);
                 Ok((uni_key, create_response.data.qrurl))
             } else {
-                Err(anyhow!("failed to get login qr url"))
+                Err(NcmError::malformed("failed to get login qr url"))
             }
         } else {
-            Err(anyhow!("failed to get login qr unikey"))
+            Err(NcmError::malformed("failed to get login qr unikey"))
         }
     }
 
     /// 检查登录二维码状态
-    pub async fn check_login_qr(&mut self, uni_key: &str) -> Result<usize> {
+    pub async fn check_login_qr(&mut self, uni_key: &str) -> NcmResult<usize> {
         let check_response = self
             .http_client
             .get(format!("{}/login/qr/check?key={}&timestamp={}", &self.api_url, &uni_key, Utc::now().timestamp()))
@@ -274,7 +403,7 @@ impl NcmClient {
     }
 
     /// 获取登录状态
-    pub async fn check_login_status(&mut self) -> Result<()> {
+    pub async fn check_login_status(&mut self) -> NcmResult<()> {
         let status_response = self
             .http_client
             .post(format!("{}/login/status", &self.api_url))
@@ -284,13 +413,12 @@ impl NcmClient {
             .bytes()
             .await?;
 
-        let mut v: Value = serde_json::from_slice(&status_response)?;
-        let v_profile = v["data"]["profile"].take();
-        if !v_profile.is_null() {
-            if let Ok(account) = Account::from_json(v_profile) {
-                debug!("login, {:?}", account);
-                self.login_account = Some(account);
-            }
+        // 该接口的状态码在 data 内，不做状态码检查
+        let status_response: LoginStatusResponse = serde_json::from_slice(&status_response)?;
+        if let Some(profile) = status_response.data.profile {
+            let account = Account::from(profile);
+            debug!("login, {:?}", account);
+            self.login_account = Some(account);
         }
 
         Ok(())
@@ -310,169 +438,516 @@ impl NcmClient {
         self.login_account.clone()
     }
 
+    /// 登录账号的用户 id ，未登录时返回 `NcmError::LoginRequired`
+    fn login_user_id(&self) -> NcmResult<u64> {
+        match self.login_account.as_ref() {
+            Some(login_account) => Ok(login_account.user_id),
+            None => Err(NcmError::LoginRequired),
+        }
+    }
+
     /// 登出
-    pub async fn logout(&mut self) -> Result<()> {
-        // TODO
+    ///
+    /// 通知服务端登出后，删除本地 cookie 文件并清除登录状态。服务端登出失败时仍会清除本地登录状态
+    pub async fn logout(&mut self) -> NcmResult<()> {
+        match self
+            .http_client
+            .post(format!("{}/logout?timestamp={}", &self.api_url, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await
+        {
+            Ok(logout_response) => {
+                if let Err(err) = parse_response::<CodeResponse>(&logout_response.bytes().await?) {
+                    error!("failed to logout: {}", err);
+                }
+            },
+            Err(err) => error!("failed to logout: {:?}", err),
+        }
+
+        // 删除 cookie 文件
+        if self.cookie_path.exists() {
+            fs::remove_file(&self.cookie_path)?;
+            debug!("cookie removed at {:?}", &self.cookie_path);
+        }
+
+        self.cookie = String::new();
+        self.login_account = None;
+        self.liked_song_ids = HashSet::new();
+        self.liked_songs_revision += 1;
+
         Ok(())
     }
 }
 
 // 用户 api
-impl NcmClient {}
-
-// 歌单 api
 impl NcmClient {
-    /// 获取用户所有歌单（创建的+收藏的）
-    pub async fn get_user_all_songlists(&self) -> Result<Vec<Songlist>> {
-        let mut songlists: Vec<Songlist> = Vec::new();
+    /// 获取用户详情（等级、累计听歌数、关注数和粉丝数等）
+    pub async fn get_user_detail(&self, user_id: u64) -> NcmResult<UserProfile> {
+        let detail_response = self
+            .http_client
+            .post(format!("{}/user/detail?uid={}&timestamp={}", &self.api_url, user_id, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
 
-        if let Some(login_account) = self.login_account.as_ref() {
-            let user_id = login_account.user_id;
+        let profile = UserProfile::from(parse_response::<UserDetailResponse>(&detail_response.bytes().await?)?);
 
-            let playlist_response = self
-                .http_client
-                .post(format!("{}/user/playlist?uid={}", &self.api_url, user_id))
-                .form(&[("cookie", &self.cookie)])
-                .send()
-                .await?;
+        debug!("user profile: {:?}", profile);
 
-            let v_playlist: Value = serde_json::from_slice(&playlist_response.bytes().await?)?;
+        Ok(profile)
+    }
 
-            // 状态码报错
-            if v_playlist["code"].as_u64().unwrap() != 200 {
-                return Err(anyhow!("failed to load songs into songlist, code {}", v_playlist["code"].as_u64().unwrap()));
-            }
-            // 仍有更多页
-            if v_playlist["more"].as_bool().unwrap() {
-                // TODO: 增加 offset ，继续获取
-            }
+    /// 获取登录账号的等级进度（需要登录）
+    pub async fn get_user_level(&self) -> NcmResult<UserLevel> {
+        self.login_user_id()?;
 
-            for playlist in v_playlist["playlist"].as_array().unwrap() {
-                songlists.push(Songlist {
-                    name: playlist["name"].as_str().unwrap().to_string(),
-                    id: playlist["id"].as_u64().unwrap(),
-                    songs_count: playlist["trackCount"].as_u64().unwrap_or(0) as usize,
-                    creator: if let Some(creator_nickname) = playlist["creator"]["nickname"].as_str() {
-                        creator_nickname.to_string()
-                    } else {
-                        String::new()
-                    },
-                    songs: Vec::new(),
-                });
-            }
+        let level_response = self
+            .http_client
+            .post(format!("{}/user/level?timestamp={}", &self.api_url, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let level_response: UserLevelResponse = parse_response(&level_response.bytes().await?)?;
 
-            debug!("songlists: {:?}", songlists);
+        Ok(UserLevel::from(level_response.data))
+    }
+
+    /// 获取用户的听歌排行（最多 100 首），按播放次数从多到少排列
+    ///
+    /// 用户设置了隐藏听歌排行时，接口返回错误状态码
+    pub async fn get_user_play_rank(&self, user_id: u64, period: RankPeriod) -> NcmResult<Vec<RankedSong>> {
+        let record_response = self
+            .http_client
+            .post(format!(
+                "{}/user/record?uid={}&type={}&timestamp={}",
+                &self.api_url,
+                user_id,
+                period.type_code(),
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let record_response: UserRecordResponse = parse_response(&record_response.bytes().await?)?;
+
+        let record_items = match period {
+            RankPeriod::Weekly => record_response.week_data,
+            RankPeriod::AllTime => record_response.all_data,
+        };
+
+        Ok(record_items.into_iter().map(RankedSong::from).collect())
+    }
+
+    /// 获取账号最近播放的歌曲（云端记录，包括在其他客户端播放的），组成虚拟歌单（需要登录）
+    pub async fn get_recent_songlist(&self) -> NcmResult<Songlist> {
+        let login_account = self.login_account.clone().ok_or(NcmError::LoginRequired)?;
+
+        let recent_response = self
+            .http_client
+            .post(format!("{}/record/recent/song?limit={}&timestamp={}", &self.api_url, RECENT_SONGS_LIMIT, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        // 跳过已下架的歌曲
+        let songs: Vec<Song> = parse_response::<RecentSongResponse>(&recent_response.bytes().await?)?
+            .data
+            .list
+            .into_iter()
+            .filter_map(|item| item.data)
+            .map(Song::from)
+            .collect();
+
+        debug!("recent songs: {:?}", songs);
+
+        Ok(Songlist {
+            name: String::from("最近播放"),
+            id: RECENT_SONGLIST_ID,
+            songs_count: songs.len(),
+            creator: login_account.nickname,
+            creator_id: 0,
+            subscribed: false,
+            songs,
+            source: SonglistSource::Recent,
+        })
+    }
+}
+
+// 歌单 api
+impl NcmClient {
+    /// 获取用户所有歌单（创建的+收藏的）
+    ///
+    /// 会逐页获取直到最后一页，需要尽早显示第一页时使用 `user_songlists_pages()`
+    pub async fn get_user_all_songlists(&self) -> NcmResult<Vec<Songlist>> {
+        let mut pages = self.user_songlists_pages()?;
+        let mut songlists = Vec::new();
+
+        while let Some(page) = pages.next_page().await? {
+            songlists.extend(page);
         }
 
+        debug!("songlists: {:?}", songlists);
+
         Ok(songlists)
     }
 
+    /// 登录用户歌单的分页游标，逐页获取时无需持有 `NcmClient` （及其锁）
+    pub fn user_songlists_pages(&self) -> NcmResult<UserSonglistsPages> {
+        Ok(UserSonglistsPages::new(
+            self.http_client.clone(),
+            self.api_url.clone(),
+            self.cookie.clone(),
+            self.login_user_id()?,
+            USER_SONGLISTS_PAGE_LIMIT,
+        ))
+    }
+
     /// 装载歌单内的所有歌曲
-    pub async fn load_songlist_songs(&self, songlist: &mut Songlist) -> Result<()> {
-        songlist.songs = Vec::new();
+    pub async fn load_songlist_songs(&self, songlist: &mut Songlist) -> NcmResult<()> {
+        songlist.songs = self.songlist_songs_loader(songlist).load(|_, _| {}).await?;
 
-        let mut offset = 0;
+        debug!("{:?}", songlist.songs);
 
-        while songlist.songs.len() % 1000 == 0 {
-            let playlist_detail_response = self
-                .http_client
-                .post(format!("{}/playlist/track/all?id={}&limit=1000&offset={}", &self.api_url, songlist.id, offset))
-                .form(&[("cookie", &self.cookie)])
-                .send()
-                .await?;
+        Ok(())
+    }
+
+    /// 歌单歌曲的加载器，根据歌曲数量并发分页获取
+    ///
+    /// 加载器持有请求所需的全部信息，加载期间无需持有 `NcmClient` （及其锁），可在后台任务中加载并报告进度
+    pub fn songlist_songs_loader(&self, songlist: &Songlist) -> SonglistSongsLoader {
+        SonglistSongsLoader::new(self.http_client.clone(), self.api_url.clone(), self.cookie.clone(), songlist.id, songlist.songs_count)
+    }
 
-            offset += 1000;
+    /// 新建歌单，返回新歌单的 id
+    pub async fn create_songlist(&self, name: &str) -> NcmResult<u64> {
+        let create_response = self
+            .http_client
+            .post(format!("{}/playlist/create", &self.api_url))
+            .query(&[("name", name.to_string()), ("timestamp", Utc::now().timestamp().to_string())])
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
 
-            let v_playlist_detail: Value = serde_json::from_slice(&playlist_detail_response.bytes().await?)?;
+        let create_response: PlaylistCreateResponse = parse_response(&create_response.bytes().await?)?;
 
-            // 状态码报错
-            if v_playlist_detail["code"].as_u64().unwrap() != 200 {
-                return Err(anyhow!("failed to load songs into songlist, code {}", v_playlist_detail["code"].as_u64().unwrap()));
-            }
-            // 获取到的歌曲列表为空
-            if v_playlist_detail["songs"].as_array().unwrap().is_empty() {
-                break;
-            }
+        debug!("created songlist `{}`: {}", name, create_response.id);
 
-            // 局部反序列化并装载
-            for track in v_playlist_detail["songs"].as_array().unwrap() {
-                let song = Song {
-                    name: track["name"].as_str().unwrap().to_string(),
-                    id: track["id"].as_u64().unwrap(),
-                    singer: track["ar"][0]["name"].as_str().unwrap_or("Unknown").to_string(),
-                    singer_id: track["ar"][0]["id"].as_u64().unwrap(),
-                    album: track["al"]["name"].as_str().unwrap_or("Unknown").to_string(),
-                    album_id: track["al"]["id"].as_u64().unwrap(),
-                    duration: track["dt"].as_u64().unwrap(),
-                    song_url: None,
-                    quality_level: String::new(),
-                };
-                songlist.songs.push(song);
-            }
-        }
+        Ok(create_response.id)
+    }
 
-        debug!("{:?}", songlist.songs);
+    /// 删除歌单
+    pub async fn delete_songlist(&self, songlist_id: u64) -> NcmResult<()> {
+        let delete_response = self
+            .http_client
+            .post(format!("{}/playlist/delete?id={}&timestamp={}", &self.api_url, songlist_id, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&delete_response.bytes().await?)?;
+
+        Ok(())
+    }
+
+    /// 重命名歌单
+    pub async fn rename_songlist(&self, songlist_id: u64, name: &str) -> NcmResult<()> {
+        let rename_response = self
+            .http_client
+            .post(format!("{}/playlist/name/update", &self.api_url))
+            .query(&[("id", songlist_id.to_string()), ("name", name.to_string()), ("timestamp", Utc::now().timestamp().to_string())])
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&rename_response.bytes().await?)?;
+
+        Ok(())
+    }
+
+    /// 向歌单中添加歌曲
+    pub async fn add_songs_to_songlist(&self, songlist_id: u64, song_ids: &[u64]) -> NcmResult<()> {
+        self.update_songlist_tracks("add", songlist_id, song_ids).await
+    }
+
+    /// 从歌单中删除歌曲
+    pub async fn remove_songs_from_songlist(&self, songlist_id: u64, song_ids: &[u64]) -> NcmResult<()> {
+        self.update_songlist_tracks("del", songlist_id, song_ids).await
+    }
+
+    /// 更新歌单内歌曲的顺序，`song_ids` 为歌单内所有歌曲按新顺序排列的 id
+    pub async fn update_songlist_order(&self, songlist_id: u64, song_ids: &[u64]) -> NcmResult<()> {
+        let ids: Vec<String> = song_ids.iter().map(u64::to_string).collect();
+
+        let order_response = self
+            .http_client
+            .post(format!("{}/song/order/update", &self.api_url))
+            .query(&[
+                ("pid", songlist_id.to_string()),
+                ("ids", format!("[{}]", ids.join(","))),
+                ("timestamp", Utc::now().timestamp().to_string()),
+            ])
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&order_response.bytes().await?)?;
+
+        debug!("updated order of songlist {}", songlist_id);
+
+        Ok(())
+    }
+
+    /// 收藏/取消收藏歌单
+    pub async fn subscribe_songlist(&self, songlist_id: u64, subscribe: bool) -> NcmResult<()> {
+        let subscribe_response = self
+            .http_client
+            .post(format!(
+                "{}/playlist/subscribe?t={}&id={}&timestamp={}",
+                &self.api_url,
+                if subscribe { 1 } else { 2 },
+                songlist_id,
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&subscribe_response.bytes().await?)?;
+
+        Ok(())
+    }
+
+    /// 歌单是否为登录用户自己创建的（只有自己创建的歌单可以修改）
+    pub fn is_own_songlist(&self, songlist: &Songlist) -> bool {
+        self.login_account.as_ref().is_some_and(|login_account| login_account.user_id == songlist.creator_id)
+    }
+
+    async fn update_songlist_tracks(&self, op: &str, songlist_id: u64, song_ids: &[u64]) -> NcmResult<()> {
+        let ids: Vec<String> = song_ids.iter().map(u64::to_string).collect();
+
+        let tracks_response = self
+            .http_client
+            .post(format!(
+                "{}/playlist/tracks?op={}&pid={}&tracks={}&timestamp={}",
+                &self.api_url,
+                op,
+                songlist_id,
+                ids.join(","),
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        check_playlist_tracks_response(&tracks_response.bytes().await?)?;
+
+        debug!("{} songs {:?} of songlist {}", op, song_ids, songlist_id);
 
         Ok(())
     }
 }
 
-// 歌曲 api
+// 专辑 api
 impl NcmClient {
-    /// 检查歌曲是否可获取
-    pub async fn check_song_availability(&self, song_id: u64) -> Result<bool> {
-        let check_response = self
+    /// 获取专辑信息及专辑内的所有歌曲
+    pub async fn get_album(&self, album_id: u64) -> NcmResult<Album> {
+        let album_response = self
             .http_client
-            .post(format!("{}/check/music?id={}", &self.api_url, song_id))
+            .post(format!("{}/album?id={}", &self.api_url, album_id))
             .form(&[("cookie", &self.cookie)])
             .send()
             .await?;
 
-        let v_check_response: Value = serde_json::from_slice(&check_response.bytes().await?)?;
+        let album_response: AlbumResponse = parse_response(&album_response.bytes().await?)?;
 
-        if v_check_response["code"].as_u64().unwrap() == 200 {
-            return Ok(v_check_response["success"].as_bool().unwrap_or(false));
-        }
+        let album = Album::from(album_response);
+
+        debug!("album: {:?}", album);
 
-        Ok(false)
+        Ok(album)
     }
+}
+
+// 歌手 api
+impl NcmClient {
+    /// 获取歌手详情
+    pub async fn get_artist_detail(&self, artist_id: u64) -> NcmResult<Artist> {
+        let artist_response = self
+            .http_client
+            .post(format!("{}/artist/detail?id={}", &self.api_url, artist_id))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
 
-    /// 装载歌曲 url
-    pub async fn load_song_url(&self, song: &mut Song) -> Result<()> {
-        song.song_url = None;
+        let artist_response: ArtistDetailResponse = parse_response(&artist_response.bytes().await?)?;
 
-        let song_url_response = self
+        let artist = Artist::from(artist_response.data.artist);
+
+        debug!("artist: {:?}", artist);
+
+        Ok(artist)
+    }
+
+    /// 获取歌手热门 50 首歌曲
+    ///
+    /// 获取到的歌曲需由调用方装入 `Artist.top_songs`
+    pub async fn get_artist_top_songs(&self, artist_id: u64) -> NcmResult<Vec<Song>> {
+        let top_songs_response = self
             .http_client
-            .post(format!("{}/song/url/v1?id={}&level={}", &self.api_url, song.id, "jymaster"))
+            .post(format!("{}/artist/top/song?id={}", &self.api_url, artist_id))
             .form(&[("cookie", &self.cookie)])
             .send()
             .await?;
 
-        let v_song_url: Value = serde_json::from_slice(&song_url_response.bytes().await?)?;
+        let top_songs_response: ArtistTopSongResponse = parse_response(&top_songs_response.bytes().await?)?;
 
-        if let Some(song_url) = v_song_url["data"][0]["url"].as_str() {
-            song.song_url = Some(song_url.to_string());
-        }
-        if let Some(quality_level) = v_song_url["data"][0]["level"].as_str() {
-            song.quality_level = match quality_level {
-                "standard" => String::from("标准"),
-                "higher" => String::from("较高"),
-                "exhigh" => String::from("极高"),
-                "lossless" => String::from("无损"),
-                "hires" => String::from("Hi-Res"),
-                "jyeffect" => String::from("高清环绕声"),
-                "sky" => String::from("沉浸环绕声"),
-                "dolby" => String::from("杜比全景声"),
-                "jymaster" => String::from("超清母带"),
-                _ => quality_level.to_string(),
-            };
+        Ok(top_songs_response.songs.into_iter().map(Song::from).collect())
+    }
+
+    /// 分页获取歌手的专辑，返回该页专辑及是否仍有更多页
+    pub async fn get_artist_albums(&self, artist_id: u64, offset: usize, limit: usize) -> NcmResult<(Vec<Album>, bool)> {
+        let albums_response = self
+            .http_client
+            .post(format!("{}/artist/album?id={}&limit={}&offset={}", &self.api_url, artist_id, limit, offset))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let albums_response: ArtistAlbumResponse = parse_response(&albums_response.bytes().await?)?;
+
+        let albums = albums_response.hot_albums.into_iter().map(Album::from).collect();
+
+        Ok((albums, albums_response.more))
+    }
+
+    /// 获取相似歌手（需登录）
+    pub async fn get_similar_artists(&self, artist_id: u64) -> NcmResult<Vec<Artist>> {
+        let similar_response = self
+            .http_client
+            .post(format!("{}/simi/artist?id={}", &self.api_url, artist_id))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let similar_response: SimiArtistResponse = parse_response(&similar_response.bytes().await?)?;
+
+        Ok(similar_response.artists.into_iter().map(Artist::from).collect())
+    }
+}
+
+// 歌曲 api
+impl NcmClient {
+    /// 获取用户喜欢的所有歌曲 id ，缓存在内存中
+    pub async fn load_liked_song_ids(&mut self) -> NcmResult<()> {
+        let user_id = self.login_user_id()?;
+
+        let likelist_response = self
+            .http_client
+            .post(format!("{}/likelist?uid={}&timestamp={}", &self.api_url, user_id, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let likelist_response: LikelistResponse = parse_response(&likelist_response.bytes().await?)?;
+
+        self.liked_song_ids = likelist_response.ids.into_iter().collect();
+        self.liked_songs_revision += 1;
+
+        debug!("liked songs count: {}", self.liked_song_ids.len());
+
+        Ok(())
+    }
+
+    /// 喜欢/取消喜欢歌曲，成功后同步更新缓存
+    pub async fn like_song(&mut self, song_id: u64, like: bool) -> NcmResult<()> {
+        let like_response = self
+            .http_client
+            .post(format!("{}/like?id={}&like={}&timestamp={}", &self.api_url, song_id, like, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&like_response.bytes().await?)?;
+
+        if like {
+            self.liked_song_ids.insert(song_id);
+        } else {
+            self.liked_song_ids.remove(&song_id);
         }
+        self.liked_songs_revision += 1;
 
         Ok(())
     }
 
+    /// 歌曲是否被喜欢（根据缓存判断）
+    pub fn is_liked(&self, song_id: u64) -> bool {
+        self.liked_song_ids.contains(&song_id)
+    }
+
+    /// 用户喜欢的所有歌曲 id （缓存）
+    pub fn liked_song_ids(&self) -> &HashSet<u64> {
+        &self.liked_song_ids
+    }
+
+    pub fn liked_songs_revision(&self) -> usize {
+        self.liked_songs_revision
+    }
+
+    /// 歌曲可获取状态的批量检查器，可在后台任务中检查
+    pub fn songs_availability_loader(&self, song_ids: &[u64]) -> SongsAvailabilityLoader {
+        SongsAvailabilityLoader::new(self.http_client.clone(), self.api_url.clone(), self.cookie.clone(), song_ids.to_vec())
+    }
+
+    /// 批量获取歌曲链接，返回每首歌曲的链接（不可获取的歌曲链接为 None）
+    pub async fn load_song_urls(&self, songs: &[Song]) -> NcmResult<HashMap<u64, SongUrl>> {
+        self.song_urls_loader(songs).load().await
+    }
+
+    /// 歌曲链接的批量加载器，可在后台任务中加载
+    pub fn song_urls_loader(&self, songs: &[Song]) -> SongUrlsLoader {
+        let candidates = songs.iter().map(|song| (song.id, self.quality_candidates(song.id))).collect();
+
+        SongUrlsLoader::new(self.http_client.clone(), self.api_url.clone(), self.cookie.clone(), candidates)
+    }
+
+    /// 歌曲依次尝试的音质（单曲覆盖 > 本次运行覆盖 > 设置中的首选音质，之后为设置中的备选音质）
+    fn quality_candidates(&self, song_id: u64) -> Vec<Quality> {
+        let mut candidates = vec![self.preferred_quality(song_id)];
+        for quality in &self.settings.quality_fallbacks {
+            if !candidates.contains(quality) {
+                candidates.push(*quality);
+            }
+        }
+
+        candidates
+    }
+
+    /// 歌曲的首选音质
+    pub fn preferred_quality(&self, song_id: u64) -> Quality {
+        match self.song_quality_overrides.get(&song_id) {
+            Some(quality) => *quality,
+            None => self.quality_override.unwrap_or(self.settings.quality),
+        }
+    }
+
+    /// 设置本次运行期间的音质，None 时恢复使用设置中的首选音质
+    pub fn set_quality_override(&mut self, quality: Option<Quality>) {
+        self.quality_override = quality;
+    }
+
+    /// 设置单曲的音质，None 时取消该歌曲的音质覆盖
+    pub fn set_song_quality_override(&mut self, song_id: u64, quality: Option<Quality>) {
+        match quality {
+            Some(quality) => self.song_quality_overrides.insert(song_id, quality),
+            None => self.song_quality_overrides.remove(&song_id),
+        };
+    }
+
     /// 获取歌曲的歌词
-    pub async fn get_song_lyrics(&self, song_id: u64) -> Result<Lyrics> {
+    pub async fn get_song_lyrics(&self, song_id: u64) -> NcmResult<Lyrics> {
         // 优先尝试从本地缓存读取歌词
         if let Ok(lyrics) = self.try_read_lyrics_cache(song_id) {
             return Ok(lyrics);
@@ -485,11 +960,9 @@ impl NcmClient {
             .send()
             .await?;
 
-        let v_lyric: Value = serde_json::from_slice(&lyric_response.bytes().await?)?;
+        let lyric_response: LyricResponse = parse_response(&lyric_response.bytes().await?)?;
 
-        let lyric_text = v_lyric["lrc"]["lyric"].as_str().unwrap_or("").to_string();
-        let trans_lyric_text = v_lyric["tlyric"]["lyric"].as_str().unwrap_or("").to_string();
-        let roman_lyric_text = v_lyric["romalrc"]["lyric"].as_str().unwrap_or("").to_string();
+        let (lyric_text, trans_lyric_text, roman_lyric_text) = lyric_response.lyric_texts();
 
         let origin_lyric_lines: Vec<String> = lyric_text.split('\n').into_iter().map(|s| s.to_string()).collect();
         let origin_trans_lyric_lines: Vec<String> = trans_lyric_text.split('\n').into_iter().map(|s| s.to_string()).collect();
@@ -528,7 +1001,7 @@ impl NcmClient {
     }
 
     /// 尝试读本地歌词缓存
-    fn try_read_lyrics_cache(&self, song_id: u64) -> Result<Lyrics> {
+    fn try_read_lyrics_cache(&self, song_id: u64) -> NcmResult<Lyrics> {
         let mut lyrics_file = File::open(self.lyrics_path.clone().join(format!("{}.lyrics", song_id)))?;
         let mut json_data = String::new();
         lyrics_file.read_to_string(&mut json_data)?;
@@ -539,6 +1012,301 @@ impl NcmClient {
     }
 }
 
+// 评论 api
+impl NcmClient {
+    /// 获取歌曲的热门评论
+    pub async fn get_song_hot_comments(&self, song_id: u64, limit: usize) -> NcmResult<Vec<Comment>> {
+        let hot_comments_response = self
+            .http_client
+            .post(format!("{}/comment/hot?id={}&type=0&limit={}", &self.api_url, song_id, limit))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let hot_comments_response: CommentHotResponse = parse_response(&hot_comments_response.bytes().await?)?;
+
+        Ok(hot_comments_response.hot_comments.into_iter().map(Comment::from).collect())
+    }
+
+    /// 分页获取歌曲的最新评论，返回该页评论及是否仍有更多页
+    pub async fn get_song_comments(&self, song_id: u64, offset: usize, limit: usize) -> NcmResult<(Vec<Comment>, bool)> {
+        let comments_response = self
+            .http_client
+            .post(format!(
+                "{}/comment/music?id={}&limit={}&offset={}&timestamp={}",
+                &self.api_url,
+                song_id,
+                limit,
+                offset,
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let comments_response: CommentMusicResponse = parse_response(&comments_response.bytes().await?)?;
+
+        let comments = comments_response.comments.into_iter().map(Comment::from).collect();
+
+        Ok((comments, comments_response.more))
+    }
+
+    /// 给歌曲的评论点赞或取消点赞（需要登录）
+    pub async fn like_comment(&self, song_id: u64, comment_id: u64, like: bool) -> NcmResult<()> {
+        let like_response = self
+            .http_client
+            .post(format!(
+                "{}/comment/like?id={}&cid={}&t={}&type=0&timestamp={}",
+                &self.api_url,
+                song_id,
+                comment_id,
+                if like { 1 } else { 0 },
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&like_response.bytes().await?)?;
+
+        Ok(())
+    }
+}
+
+// 推荐 api
+impl NcmClient {
+    /// 获取每日推荐歌曲，作为歌单返回以便直接作为播放列表（需要登录）
+    pub async fn get_daily_songlist(&self) -> NcmResult<Songlist> {
+        let recommend_response = self
+            .http_client
+            .post(format!("{}/recommend/songs?timestamp={}", &self.api_url, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let songs: Vec<Song> = parse_response::<RecommendSongsResponse>(&recommend_response.bytes().await?)?
+            .data
+            .daily_songs
+            .into_iter()
+            .map(Song::from)
+            .collect();
+
+        debug!("daily songs: {:?}", songs);
+
+        Ok(Songlist {
+            name: format!("每日推荐 ({})", Local::now().format("%Y-%m-%d")),
+            id: DAILY_SONGLIST_ID,
+            songs_count: songs.len(),
+            creator: String::from("网易云音乐"),
+            creator_id: 0,
+            subscribed: false,
+            songs,
+            source: SonglistSource::Daily,
+        })
+    }
+
+    /// 获取心动模式（智能播放）的歌曲序列，以歌单中的一首歌曲为起点（需要登录）
+    ///
+    /// 返回的序列通常以起点歌曲开头
+    pub async fn get_heart_mode_songs(&self, song_id: u64, songlist_id: u64) -> NcmResult<Vec<Song>> {
+        let intelligence_response = self
+            .http_client
+            .post(format!(
+                "{}/playmode/intelligence/list?id={}&pid={}&sid={}&timestamp={}",
+                &self.api_url,
+                song_id,
+                songlist_id,
+                song_id,
+                Utc::now().timestamp()
+            ))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let songs: Vec<Song> = parse_response::<IntelligenceListResponse>(&intelligence_response.bytes().await?)?
+            .data
+            .into_iter()
+            .map(|item| Song::from(item.song_info))
+            .collect();
+
+        debug!("heart mode songs: {:?}", songs);
+
+        Ok(songs)
+    }
+
+    /// 私人 FM 的加载器，可在后台任务中加载
+    pub fn personal_fm_loader(&self) -> PersonalFmLoader {
+        PersonalFmLoader::new(self.http_client.clone(), self.api_url.clone(), self.cookie.clone())
+    }
+
+    /// 将私人 FM 中的歌曲移入垃圾桶（不再推荐该歌曲）
+    pub async fn trash_personal_fm_song(&self, song_id: u64) -> NcmResult<()> {
+        let trash_response = self
+            .http_client
+            .post(format!("{}/fm_trash?id={}&timestamp={}", &self.api_url, song_id, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        parse_response::<CodeResponse>(&trash_response.bytes().await?)?;
+
+        Ok(())
+    }
+
+    /// 获取每日推荐歌单（只含歌单概要，需要登录）
+    pub async fn get_recommend_songlists(&self) -> NcmResult<Vec<Songlist>> {
+        let recommend_response = self
+            .http_client
+            .post(format!("{}/recommend/resource?timestamp={}", &self.api_url, Utc::now().timestamp()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let songlists: Vec<Songlist> = parse_response::<RecommendResourceResponse>(&recommend_response.bytes().await?)?
+            .recommend
+            .into_iter()
+            .map(Songlist::from)
+            .collect();
+
+        debug!("recommend songlists: {:?}", songlists);
+
+        Ok(songlists)
+    }
+}
+
+// 排行榜 api
+impl NcmClient {
+    /// 获取所有官方排行榜（只含概要），结果缓存 TOPLIST_CACHE_TTL
+    pub async fn get_toplists(&mut self) -> NcmResult<Vec<Songlist>> {
+        if let Some((fetched_at, toplists)) = self.toplists_cache.as_ref() {
+            if fetched_at.elapsed() < TOPLIST_CACHE_TTL {
+                return Ok(toplists.clone());
+            }
+        }
+
+        let toplist_response = self.http_client.post(format!("{}/toplist", &self.api_url)).form(&[("cookie", &self.cookie)]).send().await?;
+
+        let toplists: Vec<Songlist> = parse_response::<ToplistResponse>(&toplist_response.bytes().await?)?.list.into_iter().map(Songlist::from).collect();
+
+        debug!("toplists: {:?}", toplists);
+
+        self.toplists_cache = Some((Instant::now(), toplists.clone()));
+
+        Ok(toplists)
+    }
+
+    /// 加载排行榜中的歌曲（排行榜即歌单），结果缓存 TOPLIST_CACHE_TTL
+    pub async fn load_toplist_songs(&mut self, toplist: &mut Songlist) -> NcmResult<()> {
+        if let Some((fetched_at, songs)) = self.toplist_songs_cache.get(&toplist.id) {
+            if fetched_at.elapsed() < TOPLIST_CACHE_TTL {
+                toplist.songs = songs.clone();
+                return Ok(());
+            }
+        }
+
+        self.load_songlist_songs(toplist).await?;
+
+        self.toplist_songs_cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < TOPLIST_CACHE_TTL);
+        self.toplist_songs_cache.insert(toplist.id, (Instant::now(), toplist.songs.clone()));
+
+        Ok(())
+    }
+}
+
+// 电台 api
+impl NcmClient {
+    /// 获取用户订阅的所有电台（需要登录）
+    pub async fn get_subscribed_djradios(&self) -> NcmResult<Vec<DjRadio>> {
+        let mut dj_radios = Vec::new();
+
+        loop {
+            let sublist_response = self
+                .http_client
+                .post(format!(
+                    "{}/dj/sublist?limit={}&offset={}&timestamp={}",
+                    &self.api_url,
+                    SUBSCRIBED_DJRADIOS_PAGE_LIMIT,
+                    dj_radios.len(),
+                    Utc::now().timestamp()
+                ))
+                .form(&[("cookie", &self.cookie)])
+                .send()
+                .await?;
+
+            let sublist_response: DjSublistResponse = parse_response(&sublist_response.bytes().await?)?;
+            let is_empty_page = sublist_response.dj_radios.is_empty();
+            dj_radios.extend(sublist_response.dj_radios.into_iter().map(DjRadio::from));
+
+            if !sublist_response.has_more || is_empty_page {
+                break;
+            }
+        }
+
+        debug!("subscribed dj radios: {:?}", dj_radios);
+
+        Ok(dj_radios)
+    }
+
+    /// 分页获取电台的节目（最新的在前），返回该页节目及是否仍有更多页
+    pub async fn get_djradio_programs(&self, radio_id: u64, offset: usize, limit: usize) -> NcmResult<(Vec<Program>, bool)> {
+        let programs_response = self
+            .http_client
+            .post(format!("{}/dj/program?rid={}&limit={}&offset={}&asc=false", &self.api_url, radio_id, limit, offset))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let programs_response: DjProgramResponse = parse_response(&programs_response.bytes().await?)?;
+
+        let programs = programs_response.programs.into_iter().map(Program::from).collect();
+
+        Ok((programs, programs_response.more))
+    }
+
+    /// 批量获取节目音频的链接，结果以节目 id 为键（不可获取的节目链接为 None）
+    pub async fn load_program_urls(&self, programs: &[Program]) -> NcmResult<HashMap<u64, SongUrl>> {
+        let songs: Vec<Song> = programs.iter().map(Program::as_song).collect();
+        let mut song_urls = self.load_song_urls(&songs).await?;
+
+        Ok(programs
+            .iter()
+            .filter_map(|program| song_urls.remove(&program.main_song_id).map(|song_url| (program.id, song_url)))
+            .collect())
+    }
+}
+
+// 搜索 api
+impl NcmClient {
+    /// 搜索歌曲/专辑/歌手/歌单（cloudsearch），按 offset 和 limit 分页
+    pub async fn search(&self, keywords: &str, search_type: SearchType, offset: usize, limit: usize) -> NcmResult<SearchResult> {
+        let search_response = self
+            .http_client
+            .post(format!("{}/cloudsearch", &self.api_url))
+            .query(&[
+                ("keywords", keywords.to_string()),
+                ("type", search_type.type_code().to_string()),
+                ("limit", limit.to_string()),
+                ("offset", offset.to_string()),
+            ])
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let result = parse_response::<CloudsearchResponse>(&search_response.bytes().await?)?.result;
+
+        let (items, total) = match search_type {
+            SearchType::Song => (SearchItems::Songs(result.songs.into_iter().map(Song::from).collect()), result.song_count),
+            SearchType::Album => (SearchItems::Albums(result.albums.into_iter().map(Album::from).collect()), result.album_count),
+            SearchType::Artist => (SearchItems::Artists(result.artists.into_iter().map(Artist::from).collect()), result.artist_count),
+            SearchType::Songlist => (SearchItems::Songlists(result.playlists.into_iter().map(Songlist::from).collect()), result.playlist_count),
+        };
+
+        debug!("search `{}` ({:?}), offset {}: {:?}", keywords, search_type, offset, items);
+
+        Ok(SearchResult { total, items, offset })
+    }
+}
+
 #[inline]
 /// 编码并序列化歌词
 fn encode_lyrics(origin_lyric_lines: Vec<String>, origin_trans_lyric_lines: Vec<String>, origin_roman_lyric_lines: Vec<String>) -> Lyrics {
@@ -1,15 +1,23 @@
 pub mod account;
+pub mod album;
+pub mod artist;
+pub mod comment;
 pub mod lyric;
+pub mod podcast;
+pub mod quality;
+pub mod search;
 pub mod song;
 pub mod songlist;
+pub mod user;
 
 pub use account::*;
+pub use album::*;
+pub use artist::*;
+pub use comment::*;
 pub use lyric::*;
+pub use podcast::*;
+pub use quality::*;
+pub use search::*;
 pub use song::*;
 pub use songlist::*;
-
-pub trait FromJson {
-    type SelfType;
-
-    fn from_json(value: serde_json::Value) -> anyhow::Result<Self::SelfType>;
-}
+pub use user::*;
@@ -0,0 +1,50 @@
+use crate::model::{Song, Songlist, SonglistSource};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct Album {
+    /// 专辑名
+    pub name: String,
+    /// 专辑 id
+    pub id: u64,
+    /// 歌手
+    pub artist: String,
+    /// 歌手 id
+    pub artist_id: u64,
+    /// 歌曲数量
+    pub songs_count: usize,
+    /// 发行时间（ms）
+    pub publish_time: i64,
+    /// 发行公司
+    pub company: String,
+    /// 专辑介绍
+    pub description: String,
+    /// 专辑内的歌曲
+    pub songs: Vec<Song>,
+}
+
+impl Album {
+    /// 发行日期（yyyy-mm-dd）
+    pub fn publish_date(&self) -> String {
+        match DateTime::from_timestamp_millis(self.publish_time) {
+            Some(date_time) => date_time.format("%Y-%m-%d").to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// 转换为歌单，以便作为播放列表
+    pub fn as_songlist(&self) -> Songlist {
+        Songlist {
+            name: self.name.clone(),
+            id: self.id,
+            songs_count: self.songs.len(),
+            creator: self.artist.clone(),
+            creator_id: 0,
+            subscribed: false,
+            songs: self.songs.clone(),
+            source: SonglistSource::Album,
+        }
+    }
+}
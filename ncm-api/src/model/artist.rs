@@ -0,0 +1,35 @@
+use crate::model::{Song, Songlist, SonglistSource};
+use serde::{Deserialize, Serialize};
+
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct Artist {
+    /// 歌手名
+    pub name: String,
+    /// 歌手 id
+    pub id: u64,
+    /// 专辑数量
+    pub albums_count: usize,
+    /// 歌曲数量
+    pub songs_count: usize,
+    /// 简介（仅歌手详情中有）
+    pub brief_desc: String,
+    /// 热门歌曲
+    pub top_songs: Vec<Song>,
+}
+
+impl Artist {
+    /// 将热门歌曲转换为歌单，以便作为播放列表
+    pub fn as_songlist(&self) -> Songlist {
+        Songlist {
+            name: format!("{} 热门歌曲", self.name),
+            id: self.id,
+            songs_count: self.top_songs.len(),
+            creator: self.name.clone(),
+            creator_id: 0,
+            subscribed: false,
+            songs: self.top_songs.clone(),
+            source: SonglistSource::Artist,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// 歌曲评论
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct Comment {
+    /// 评论 id
+    pub id: u64,
+    /// 评论者昵称
+    pub user_name: String,
+    /// 评论者的用户 id
+    pub user_id: u64,
+    /// 评论内容
+    pub content: String,
+    /// 点赞数
+    pub liked_count: u64,
+    /// 登录用户是否已点赞
+    pub liked: bool,
+    /// 评论时间（ms）
+    pub time: i64,
+}
+
+impl Comment {
+    /// 评论时间（yyyy-mm-dd hh:mm，本地时区）
+    pub fn time_str(&self) -> String {
+        match DateTime::from_timestamp_millis(self.time) {
+            Some(date_time) => date_time.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string(),
+            None => String::new(),
+        }
+    }
+}
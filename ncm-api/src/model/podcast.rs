@@ -0,0 +1,107 @@
+use crate::model::{Artist, Song, SongAvailability, Songlist, SonglistSource};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// 电台（播客）
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct DjRadio {
+    /// 电台名
+    pub name: String,
+    /// 电台 id
+    pub id: u64,
+    /// 主播
+    pub dj_name: String,
+    /// 分类
+    pub category: String,
+    /// 节目数量
+    pub programs_count: usize,
+    /// 最新一期节目名
+    pub last_program_name: String,
+    /// 封面链接
+    pub cover_url: Option<String>,
+}
+
+impl DjRadio {
+    /// 将已加载的节目转换为歌单，以便在列表中显示或作为播放列表
+    pub fn as_songlist(&self, programs: &[Program]) -> Songlist {
+        Songlist {
+            name: self.name.clone(),
+            id: self.id,
+            songs_count: self.programs_count,
+            creator: self.dj_name.clone(),
+            creator_id: 0,
+            subscribed: true,
+            songs: programs.iter().map(Program::as_song).collect(),
+            source: SonglistSource::DjRadio,
+        }
+    }
+}
+
+/// 电台节目（单集）
+///
+/// 节目的音频是一首独立的歌曲（`main_song_id`），链接与歌词都通过该 id 获取
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct Program {
+    /// 节目名
+    pub name: String,
+    /// 节目 id
+    pub id: u64,
+    /// 节目音频的歌曲 id
+    pub main_song_id: u64,
+    /// 所属电台名
+    pub radio_name: String,
+    /// 所属电台 id
+    pub radio_id: u64,
+    /// 主播
+    pub dj_name: String,
+    /// 期数
+    pub serial_num: u32,
+    /// 时长（ms）
+    pub duration: u64,
+    /// 发布时间（ms）
+    pub create_time: i64,
+    /// 节目介绍
+    pub description: String,
+    /// 封面链接
+    pub cover_url: Option<String>,
+}
+
+impl Program {
+    /// 发布日期（yyyy-mm-dd）
+    pub fn create_date(&self) -> String {
+        match DateTime::from_timestamp_millis(self.create_time) {
+            Some(date_time) => date_time.format("%Y-%m-%d").to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// 转换为歌曲，以便加入播放列表：歌曲 id 为节目音频的 id ，歌手为主播，专辑为所属电台
+    pub fn as_song(&self) -> Song {
+        Song {
+            name: self.name.clone(),
+            id: self.main_song_id,
+            artists: vec![Artist {
+                name: self.dj_name.clone(),
+                id: 0,
+                albums_count: 0,
+                songs_count: 0,
+                brief_desc: String::new(),
+                top_songs: Vec::new(),
+            }],
+            alias: Vec::new(),
+            trans_name: None,
+            album: self.radio_name.clone(),
+            album_id: 0,
+            cover_url: self.cover_url.clone(),
+            duration: self.duration,
+            fee: 0,
+            popularity: 0,
+            track_no: self.serial_num,
+            song_url: None,
+            quality_level: String::new(),
+            availability: SongAvailability::Unknown,
+        }
+    }
+}
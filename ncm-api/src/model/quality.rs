@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 音质等级，对应 `/song/url/v1` 接口的 level 参数
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Quality {
+    /// 标准
+    Standard,
+    /// 较高
+    Higher,
+    /// 极高
+    Exhigh,
+    /// 无损
+    Lossless,
+    /// Hi-Res
+    Hires,
+    /// 高清环绕声
+    Jyeffect,
+    /// 沉浸环绕声
+    Sky,
+    /// 杜比全景声
+    Dolby,
+    /// 超清母带
+    Jymaster,
+}
+
+impl Quality {
+    /// 所有音质等级，由低到高
+    pub const ALL: [Quality; 9] = [
+        Quality::Standard,
+        Quality::Higher,
+        Quality::Exhigh,
+        Quality::Lossless,
+        Quality::Hires,
+        Quality::Jyeffect,
+        Quality::Sky,
+        Quality::Dolby,
+        Quality::Jymaster,
+    ];
+
+    /// 接口使用的 level 参数
+    pub fn level(&self) -> &'static str {
+        match self {
+            Quality::Standard => "standard",
+            Quality::Higher => "higher",
+            Quality::Exhigh => "exhigh",
+            Quality::Lossless => "lossless",
+            Quality::Hires => "hires",
+            Quality::Jyeffect => "jyeffect",
+            Quality::Sky => "sky",
+            Quality::Dolby => "dolby",
+            Quality::Jymaster => "jymaster",
+        }
+    }
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Quality::Standard => "标准",
+            Quality::Higher => "较高",
+            Quality::Exhigh => "极高",
+            Quality::Lossless => "无损",
+            Quality::Hires => "Hi-Res",
+            Quality::Jyeffect => "高清环绕声",
+            Quality::Sky => "沉浸环绕声",
+            Quality::Dolby => "杜比全景声",
+            Quality::Jymaster => "超清母带",
+        }
+    }
+}
+
+impl FromStr for Quality {
+    type Err = String;
+
+    /// 从 level 参数解析
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        Quality::ALL
+            .into_iter()
+            .find(|quality| quality.level() == level)
+            .ok_or_else(|| format!("unknown quality level: {}", level))
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::model::{Album, Artist, Song, Songlist};
+
+/// 搜索类型
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SearchType {
+    Song,
+    Album,
+    Artist,
+    Songlist,
+}
+
+impl SearchType {
+    /// cloudsearch 接口的 type 参数
+    pub fn type_code(&self) -> u64 {
+        match self {
+            SearchType::Song => 1,
+            SearchType::Album => 10,
+            SearchType::Artist => 100,
+            SearchType::Songlist => 1000,
+        }
+    }
+}
+
+/// 搜索结果（一页）
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SearchItems {
+    Songs(Vec<Song>),
+    Albums(Vec<Album>),
+    Artists(Vec<Artist>),
+    Songlists(Vec<Songlist>),
+}
+
+impl SearchItems {
+    pub fn len(&self) -> usize {
+        match self {
+            SearchItems::Songs(songs) => songs.len(),
+            SearchItems::Albums(albums) => albums.len(),
+            SearchItems::Artists(artists) => artists.len(),
+            SearchItems::Songlists(songlists) => songlists.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SearchResult {
+    /// 本页的结果
+    pub items: SearchItems,
+    /// 结果总数
+    pub total: usize,
+    /// 本页在全部结果中的偏移
+    pub offset: usize,
+}
+
+impl SearchResult {
+    /// 是否还有下一页
+    pub fn has_more(&self) -> bool {
+        self.offset + self.items.len() < self.total
+    }
+}
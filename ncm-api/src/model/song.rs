@@ -1,3 +1,5 @@
+use crate::model::Artist;
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 #[allow(unused)]
@@ -7,18 +9,128 @@ pub struct Song {
     pub name: String,
     /// 歌曲 id
     pub id: u64,
-    /// 歌手
-    pub singer: String,
-    /// 歌手 id
-    pub singer_id: u64,
+    /// 歌手（合唱、合作歌曲有多位）
+    pub artists: Vec<Artist>,
+    /// 别名
+    pub alias: Vec<String>,
+    /// 译名
+    pub trans_name: Option<String>,
     /// 专辑
     pub album: String,
     /// 专辑 id
     pub album_id: u64,
+    /// 封面链接
+    pub cover_url: Option<String>,
     /// 歌曲时长
     pub duration: u64,
+    /// 付费类型（0、8：免费，1：VIP 歌曲，4：购买专辑）
+    pub fee: i64,
+    /// 热度（0~100）
+    pub popularity: u32,
+    /// 在专辑中的曲目序号（未知时为 0）
+    pub track_no: u32,
     /// 歌曲链接
     pub song_url: Option<String>,
     /// 音质
     pub quality_level: String,
+    /// 可获取状态（版权/会员/试听限制）
+    #[serde(default)]
+    pub availability: SongAvailability,
+}
+
+impl Song {
+    /// 所有歌手名，以 `/` 分隔
+    pub fn singer(&self) -> String {
+        if self.artists.is_empty() {
+            String::from("Unknown")
+        } else {
+            self.artists.iter().map(|artist| artist.name.as_str()).collect::<Vec<&str>>().join("/")
+        }
+    }
+
+    /// 歌名，带上译名或别名（如有）
+    pub fn full_name(&self) -> String {
+        match self.trans_name.as_ref().or(self.alias.first()) {
+            Some(extra_name) => format!("{} ({})", self.name, extra_name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// 是否为 VIP 歌曲
+    pub fn is_vip_only(&self) -> bool {
+        self.fee == 1
+    }
+
+    /// 是否需要购买专辑
+    pub fn is_paid_album(&self) -> bool {
+        self.fee == 4
+    }
+
+    /// 使用获取到的歌曲链接
+    pub fn set_song_url(&mut self, song_url: &SongUrl) {
+        self.song_url = song_url.url.clone();
+        self.quality_level = song_url.quality_level.clone();
+    }
+}
+
+/// 歌曲可获取状态
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize, Clone, Copy)]
+pub enum SongAvailability {
+    /// 尚未检查
+    #[default]
+    Unknown,
+    /// 可完整播放
+    Available,
+    /// 只能试听片段
+    TrialOnly,
+    /// VIP 歌曲或需购买专辑
+    VipOnly,
+    /// 无版权
+    NoCopyright,
+}
+
+impl SongAvailability {
+    /// 是否可以完整播放（尚未检查的歌曲视为可以）
+    pub fn is_playable(&self) -> bool {
+        matches!(self, SongAvailability::Unknown | SongAvailability::Available)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SongAvailability::Unknown => "未知",
+            SongAvailability::Available => "可播放",
+            SongAvailability::TrialOnly => "仅试听",
+            SongAvailability::VipOnly => "VIP",
+            SongAvailability::NoCopyright => "无版权",
+        }
+    }
+}
+
+/// 歌曲链接（有时效）
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SongUrl {
+    /// 歌曲 id
+    pub song_id: u64,
+    /// 链接，歌曲不可获取时为 None
+    pub url: Option<String>,
+    /// 实际获取到的音质
+    pub quality_level: String,
+    /// 可获取状态
+    pub availability: SongAvailability,
+    /// 过期时间（ms 时间戳）
+    pub expires_at: i64,
+}
+
+impl SongUrl {
+    /// 距离过期不足 1 分钟时即视为过期，留出缓冲时间
+    const EXPIRE_MARGIN_MS: i64 = 60 * 1000;
+
+    pub fn is_available(&self) -> bool {
+        self.url.is_some()
+    }
+
+    /// 是否（即将）过期
+    pub fn is_expired(&self) -> bool {
+        Utc::now().timestamp_millis() + Self::EXPIRE_MARGIN_MS >= self.expires_at
+    }
 }
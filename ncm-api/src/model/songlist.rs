@@ -16,6 +16,50 @@ pub struct Songlist {
     /// 创建者
     pub creator: String,
 
+    /// 创建者的用户 id （专辑、搜索结果等临时歌单为 0）
+    #[serde(default)]
+    pub creator_id: u64,
+
+    /// 是否为登录用户收藏的歌单（用户歌单中区分创建的和收藏的）
+    #[serde(default)]
+    pub subscribed: bool,
+
     /// 歌单内的歌曲
     pub songs: Vec<Song>,
+
+    /// 来源（专辑等临时歌单的 id 不是歌单 id）
+    #[serde(default)]
+    pub source: SonglistSource,
+}
+
+impl Songlist {
+    /// 网易云音乐的歌单 id ，专辑等临时歌单为 None
+    pub fn songlist_id(&self) -> Option<u64> {
+        match self.source {
+            SonglistSource::Songlist => Some(self.id),
+            _ => None,
+        }
+    }
+}
+
+/// 歌单的来源
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize, Clone, Copy)]
+pub enum SonglistSource {
+    /// 网易云音乐的歌单（包括排行榜）
+    #[default]
+    Songlist,
+    /// 专辑，id 为专辑 id
+    Album,
+    /// 歌手热门歌曲，id 为歌手 id
+    Artist,
+    /// 搜索到的歌曲，没有 id
+    Search,
+    /// 用户的听歌排行，id 为用户 id
+    Rank,
+    /// 电台节目，id 为电台 id
+    DjRadio,
+    /// 每日推荐歌曲组成的虚拟歌单，id 为 `DAILY_SONGLIST_ID`
+    Daily,
+    /// 最近播放歌曲组成的虚拟歌单，id 为 `RECENT_SONGLIST_ID`
+    Recent,
 }
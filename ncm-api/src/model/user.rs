@@ -0,0 +1,97 @@
+use crate::model::{Song, Songlist, SonglistSource};
+use serde::{Deserialize, Serialize};
+
+/// 用户详情
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct UserProfile {
+    /// 用户 id
+    pub user_id: u64,
+    /// 昵称
+    pub nickname: String,
+    /// 个性签名
+    pub signature: String,
+    /// 会员类型（0 为非会员）
+    pub vip_type: i64,
+    /// 等级
+    pub level: u32,
+    /// 累计听歌数
+    pub listen_songs: u64,
+    /// 关注数
+    pub follows: u64,
+    /// 粉丝数
+    pub followeds: u64,
+    /// 创建和收藏的歌单数
+    pub playlist_count: u64,
+    /// 注册天数
+    pub create_days: u64,
+}
+
+impl UserProfile {
+    /// 将听歌排行转换为歌单，以便作为播放列表
+    pub fn rank_songlist(&self, period: RankPeriod, ranked_songs: &[RankedSong]) -> Songlist {
+        Songlist {
+            name: format!("{}最常听", period.name()),
+            id: self.user_id,
+            songs_count: ranked_songs.len(),
+            creator: self.nickname.clone(),
+            creator_id: self.user_id,
+            subscribed: false,
+            songs: ranked_songs.iter().map(|ranked_song| ranked_song.song.clone()).collect(),
+            source: SonglistSource::Rank,
+        }
+    }
+}
+
+/// 登录账号的等级进度
+#[allow(unused)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+pub struct UserLevel {
+    /// 当前等级
+    pub level: u32,
+    /// 升级进度（0~1）
+    pub progress: f64,
+    /// 已听歌数
+    pub now_play_count: u64,
+    /// 升到下一级所需听歌数
+    pub next_play_count: u64,
+    /// 已登录天数
+    pub now_login_count: u64,
+    /// 升到下一级所需登录天数
+    pub next_login_count: u64,
+}
+
+/// 听歌排行的统计时段
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RankPeriod {
+    Weekly,
+    AllTime,
+}
+
+impl RankPeriod {
+    /// user/record 接口的 type 参数
+    pub fn type_code(&self) -> u64 {
+        match self {
+            RankPeriod::Weekly => 1,
+            RankPeriod::AllTime => 0,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RankPeriod::Weekly => "最近一周",
+            RankPeriod::AllTime => "所有时间",
+        }
+    }
+}
+
+/// 听歌排行中的歌曲
+#[allow(unused)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+pub struct RankedSong {
+    pub song: Song,
+    /// 播放次数
+    pub play_count: u64,
+    /// 相对排行第一的歌曲的得分（0~100）
+    pub score: u64,
+}
@@ -0,0 +1,228 @@
+use crate::model::{Song, Songlist};
+use crate::responses::parse_response;
+use crate::responses::songlist::{PlaylistTrackAllResponse, UserPlaylistResponse};
+use crate::{NcmError, NcmResult};
+use log::debug;
+use reqwest::Client;
+use tokio::task::JoinSet;
+
+/// 歌单歌曲每页的数量
+const SONGLIST_SONGS_PAGE_LIMIT: usize = 1000;
+/// 同时进行的分页请求数量上限
+const MAX_CONCURRENT_PAGE_REQUESTS: usize = 4;
+
+/// 用户歌单的分页游标
+///
+/// 由 `NcmClient::user_songlists_pages()` 创建，每次调用 `next_page()` 获取一页并前进。
+/// 游标持有请求所需的全部信息，获取期间无需持有 `NcmClient` （及其锁），让界面先显示已获取的歌单
+#[derive(Debug, Clone)]
+pub struct UserSonglistsPages {
+    http_client: Client,
+    api_url: String,
+    cookie: String,
+    user_id: u64,
+    offset: usize,
+    limit: usize,
+    has_more: bool,
+}
+
+impl UserSonglistsPages {
+    pub(crate) fn new(http_client: Client, api_url: String, cookie: String, user_id: u64, limit: usize) -> Self {
+        Self {
+            http_client,
+            api_url,
+            cookie,
+            user_id,
+            offset: 0,
+            limit,
+            has_more: true,
+        }
+    }
+
+    /// 歌单所属用户的 id
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    /// 下一页在全部歌单中的偏移
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// 是否仍有下一页
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// 获取下一页歌单，已无更多页时返回 None
+    pub async fn next_page(&mut self) -> NcmResult<Option<Vec<Songlist>>> {
+        if !self.has_more {
+            return Ok(None);
+        }
+
+        let playlist_response = self
+            .http_client
+            .post(format!("{}/user/playlist?uid={}&limit={}&offset={}", &self.api_url, self.user_id, self.limit, self.offset))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let playlist_response: UserPlaylistResponse = parse_response(&playlist_response.bytes().await?)?;
+
+        let songlists: Vec<Songlist> = playlist_response.playlist.into_iter().map(Songlist::from).collect();
+
+        debug!("songlists page at {}: {} songlists, more: {}", self.offset, songlists.len(), playlist_response.more);
+
+        // 返回空页时不再继续，防止死循环
+        self.advance(songlists.len(), playlist_response.more && !songlists.is_empty());
+
+        Ok(Some(songlists))
+    }
+
+    /// 获取到一页后前进
+    fn advance(&mut self, page_len: usize, has_more: bool) {
+        self.offset += page_len;
+        self.has_more = has_more;
+    }
+}
+
+/// 歌单歌曲的加载器
+///
+/// 由 `NcmClient::songlist_songs_loader()` 创建。根据歌单的歌曲数量（`trackCount`）计算页数，
+/// 以有限的并发数同时获取各页，再按页序拼接
+#[derive(Debug, Clone)]
+pub struct SonglistSongsLoader {
+    http_client: Client,
+    api_url: String,
+    cookie: String,
+    songlist_id: u64,
+    songs_count: usize,
+}
+
+impl SonglistSongsLoader {
+    pub(crate) fn new(http_client: Client, api_url: String, cookie: String, songlist_id: u64, songs_count: usize) -> Self {
+        Self {
+            http_client,
+            api_url,
+            cookie,
+            songlist_id,
+            songs_count,
+        }
+    }
+
+    /// 总页数（歌曲数量未知时按 1 页计）
+    pub fn pages_count(&self) -> usize {
+        self.songs_count.div_ceil(SONGLIST_SONGS_PAGE_LIMIT).max(1)
+    }
+
+    /// 加载所有歌曲，每获取到一页调用一次 `on_progress(已获取页数, 总页数)`
+    ///
+    /// 任意一页失败时返回错误，其余未完成的请求随之取消
+    pub async fn load(self, mut on_progress: impl FnMut(usize, usize)) -> NcmResult<Vec<Song>> {
+        let pages_count = self.pages_count();
+        let mut pages: Vec<Vec<Song>> = vec![Vec::new(); pages_count];
+        let mut join_set = JoinSet::new();
+        let mut next_page_index = 0;
+        let mut loaded_pages_count = 0;
+
+        on_progress(loaded_pages_count, pages_count);
+
+        while loaded_pages_count < pages_count {
+            // 补足并发请求
+            while next_page_index < pages_count && join_set.len() < MAX_CONCURRENT_PAGE_REQUESTS {
+                let page_request = self.page_request(next_page_index * SONGLIST_SONGS_PAGE_LIMIT);
+                let page_index = next_page_index;
+                join_set.spawn(async move { (page_index, page_request.await) });
+                next_page_index += 1;
+            }
+
+            match join_set.join_next().await {
+                Some(Ok((page_index, page_result))) => {
+                    pages[page_index] = page_result?;
+                    loaded_pages_count += 1;
+                    on_progress(loaded_pages_count, pages_count);
+                },
+                Some(Err(err)) => return Err(NcmError::Network(format!("page request aborted: {}", err))),
+                None => break,
+            }
+        }
+
+        let mut last_page_len = pages.last().map(Vec::len).unwrap_or(0);
+        let mut songs: Vec<Song> = pages.into_iter().flatten().collect();
+
+        // trackCount 偏小（如歌单刚刚更新）时，最后一页是满的，继续逐页获取直到不满一页
+        while self.has_more_pages(songs.len(), last_page_len) {
+            let page = self.page_request(songs.len()).await?;
+            last_page_len = page.len();
+            songs.extend(page);
+        }
+
+        debug!("loaded {} songs of songlist {} in {} pages", songs.len(), self.songlist_id, pages_count);
+
+        Ok(songs)
+    }
+
+    /// 按 trackCount 获取完各页后是否仍需继续获取
+    ///
+    /// 仅在 trackCount 确定偏小（已获取的歌曲多于 trackCount ，或 trackCount 为 0）且最后一页是满的时继续获取，
+    /// trackCount 恰为整页数时不多请求一页
+    fn has_more_pages(&self, loaded_songs_count: usize, last_page_len: usize) -> bool {
+        last_page_len == SONGLIST_SONGS_PAGE_LIMIT && (loaded_songs_count > self.songs_count || self.songs_count == 0)
+    }
+
+    /// 获取从 offset 开始的一页歌曲
+    fn page_request(&self, offset: usize) -> impl std::future::Future<Output = NcmResult<Vec<Song>>> + Send + 'static {
+        let request = self
+            .http_client
+            .post(format!(
+                "{}/playlist/track/all?id={}&limit={}&offset={}",
+                &self.api_url, self.songlist_id, SONGLIST_SONGS_PAGE_LIMIT, offset
+            ))
+            .form(&[("cookie", &self.cookie)]);
+
+        async move {
+            let playlist_detail_response: PlaylistTrackAllResponse = parse_response(&request.send().await?.bytes().await?)?;
+
+            Ok(playlist_detail_response.songs.into_iter().map(Song::from).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loader_with_songs_count(songs_count: usize) -> SonglistSongsLoader {
+        SonglistSongsLoader::new(Client::new(), String::new(), String::new(), 0, songs_count)
+    }
+
+    #[test]
+    fn pages_count_from_songs_count() {
+        assert_eq!(loader_with_songs_count(0).pages_count(), 1);
+        assert_eq!(loader_with_songs_count(999).pages_count(), 1);
+        // 恰好为整页时不多请求一页
+        assert_eq!(loader_with_songs_count(1000).pages_count(), 1);
+        assert_eq!(loader_with_songs_count(1001).pages_count(), 2);
+        assert_eq!(loader_with_songs_count(3000).pages_count(), 3);
+    }
+
+    #[test]
+    fn has_more_pages_when_track_count_is_stale() {
+        // trackCount 准确且最后一页不满
+        assert!(!loader_with_songs_count(1500).has_more_pages(1500, 500));
+        // trackCount 恰为整页数时不多请求一页
+        assert!(!loader_with_songs_count(1000).has_more_pages(1000, 1000));
+        assert!(!loader_with_songs_count(2000).has_more_pages(2000, 1000));
+        // trackCount 偏小，最后一页是满的，继续获取
+        assert!(loader_with_songs_count(1500).has_more_pages(2000, 1000));
+        assert!(loader_with_songs_count(1500).has_more_pages(3000, 1000));
+        // trackCount 为 0 时无法判断，最后一页是满的就继续获取
+        assert!(loader_with_songs_count(0).has_more_pages(1000, 1000));
+        // 继续获取到的页不满时停止
+        assert!(!loader_with_songs_count(2000).has_more_pages(2300, 300));
+    }
+}
@@ -0,0 +1,39 @@
+use crate::model::Song;
+use crate::responses::parse_response;
+use crate::responses::recommend::PersonalFmResponse;
+use crate::NcmResult;
+use chrono::Utc;
+use log::debug;
+use reqwest::Client;
+
+/// 私人 FM 的加载器
+///
+/// 由 `NcmClient::personal_fm_loader()` 创建。加载期间无需持有 `NcmClient` （及其锁），可在后台任务中补充待播歌曲
+#[derive(Debug, Clone)]
+pub struct PersonalFmLoader {
+    http_client: Client,
+    api_url: String,
+    cookie: String,
+}
+
+impl PersonalFmLoader {
+    pub(crate) fn new(http_client: Client, api_url: String, cookie: String) -> Self {
+        Self { http_client, api_url, cookie }
+    }
+
+    /// 获取下一批私人 FM 歌曲（每次数首，需要登录）
+    pub async fn load(self) -> NcmResult<Vec<Song>> {
+        let personal_fm_response = self
+            .http_client
+            .post(format!("{}/personal_fm?timestamp={}", &self.api_url, Utc::now().timestamp_millis()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let songs: Vec<Song> = parse_response::<PersonalFmResponse>(&personal_fm_response.bytes().await?)?.data.into_iter().map(Song::from).collect();
+
+        debug!("loaded {} personal fm songs", songs.len());
+
+        Ok(songs)
+    }
+}
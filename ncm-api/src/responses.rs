@@ -1 +1,66 @@
+pub mod album;
+pub mod artist;
+pub mod comment;
 pub mod login;
+pub mod podcast;
+pub mod recommend;
+pub mod search;
+pub mod song;
+pub mod songlist;
+pub mod user;
+
+use crate::error::{check_response_code, NcmResult};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// 检查状态码后，将响应解析为对应的类型
+pub fn parse_response<T: DeserializeOwned>(bytes: &[u8]) -> NcmResult<T> {
+    let v: Value = serde_json::from_slice(bytes)?;
+
+    // 状态码报错
+    check_response_code(&v)?;
+
+    Ok(serde_json::from_value(v)?)
+}
+
+/// 只关心状态码的响应（如 `/like` 、`/logout`）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct CodeResponse {
+    pub code: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NcmError;
+
+    #[test]
+    fn parse_code_response() {
+        let response: CodeResponse = parse_response(br#"{"code": 200}"#).unwrap();
+        assert_eq!(response.code, 200);
+    }
+
+    #[test]
+    fn non_200_code_is_error() {
+        assert_eq!(parse_response::<CodeResponse>(r#"{"code": 301, "msg": "需要登录"}"#.as_bytes()).unwrap_err(), NcmError::LoginRequired);
+        assert_eq!(
+            parse_response::<CodeResponse>(r#"{"code": -460, "message": "网络太拥挤"}"#.as_bytes()).unwrap_err(),
+            NcmError::RateLimited
+        );
+        assert_eq!(
+            parse_response::<CodeResponse>(r#"{"code": 404, "message": "资源不存在"}"#.as_bytes()).unwrap_err(),
+            NcmError::ApiCode {
+                code: 404,
+                message: String::from("资源不存在")
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_response_is_error() {
+        assert!(matches!(parse_response::<CodeResponse>(b"<html></html>"), Err(NcmError::MalformedResponse(_))));
+        assert!(matches!(parse_response::<CodeResponse>(br#"{"data": {}}"#), Err(NcmError::MalformedResponse(_))));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::model::Album;
+use crate::responses::song::SongItem;
+use serde::Deserialize;
+
+/// 专辑概要（`album` 或 `hotAlbums` / `albums` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AlbumItem {
+    pub name: String,
+    pub id: u64,
+    pub artist: Option<AlbumArtistItem>,
+    #[serde(default)]
+    pub size: Option<usize>,
+    #[serde(default)]
+    pub publish_time: Option<i64>,
+    pub company: Option<String>,
+    pub description: Option<String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct AlbumArtistItem {
+    #[serde(default)]
+    pub id: u64,
+    pub name: Option<String>,
+}
+
+impl From<AlbumItem> for Album {
+    /// 不含专辑内的歌曲
+    fn from(item: AlbumItem) -> Self {
+        let (artist, artist_id) = match item.artist {
+            Some(artist) => (artist.name.unwrap_or_else(|| String::from("Unknown")), artist.id),
+            None => (String::from("Unknown"), 0),
+        };
+
+        Album {
+            name: item.name,
+            id: item.id,
+            artist,
+            artist_id,
+            songs_count: item.size.unwrap_or(0),
+            publish_time: item.publish_time.unwrap_or(0),
+            company: item.company.unwrap_or_default(),
+            description: item.description.unwrap_or_default(),
+            songs: Vec::new(),
+        }
+    }
+}
+
+/// `/album`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct AlbumResponse {
+    pub album: AlbumItem,
+    #[serde(default)]
+    pub songs: Vec<SongItem>,
+}
+
+impl From<AlbumResponse> for Album {
+    fn from(response: AlbumResponse) -> Self {
+        let mut album = Album::from(response.album);
+        album.songs = response.songs.into_iter().map(Into::into).collect();
+
+        album
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_album_response() {
+        let response: AlbumResponse = parse_response(include_bytes!("../../tests/fixtures/album.json")).unwrap();
+        let album = Album::from(response);
+
+        assert_eq!(album.name, "叶惠美");
+        assert_eq!(album.id, 18905);
+        assert_eq!(album.artist, "周杰伦");
+        assert_eq!(album.artist_id, 6452);
+        assert_eq!(album.songs_count, 11);
+        assert_eq!(album.publish_time, 1059580800000);
+        assert_eq!(album.company, "索尼音乐");
+        assert_eq!(album.songs.len(), 1);
+        assert_eq!(album.songs[0].album_id, 18905);
+
+        // 专辑 id 不是歌单 id
+        assert_eq!(album.as_songlist().songlist_id(), None);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::model::Artist;
+use crate::responses::album::AlbumItem;
+use crate::responses::song::SongItem;
+use serde::Deserialize;
+
+/// 歌手概要（`artist` 或 `artists` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub album_size: Option<usize>,
+    #[serde(default)]
+    pub music_size: Option<usize>,
+    pub brief_desc: Option<String>,
+}
+
+impl From<ArtistItem> for Artist {
+    /// 不含热门歌曲
+    fn from(item: ArtistItem) -> Self {
+        Artist {
+            name: item.name,
+            id: item.id,
+            albums_count: item.album_size.unwrap_or(0),
+            songs_count: item.music_size.unwrap_or(0),
+            brief_desc: item.brief_desc.unwrap_or_default(),
+            top_songs: Vec::new(),
+        }
+    }
+}
+
+/// `/artist/detail`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ArtistDetailResponse {
+    pub data: ArtistDetailData,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ArtistDetailData {
+    pub artist: ArtistItem,
+}
+
+/// `/artist/top/song`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ArtistTopSongResponse {
+    #[serde(default)]
+    pub songs: Vec<SongItem>,
+}
+
+/// `/artist/album`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistAlbumResponse {
+    #[serde(default)]
+    pub hot_albums: Vec<AlbumItem>,
+    #[serde(default)]
+    pub more: bool,
+}
+
+/// `/simi/artist`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SimiArtistResponse {
+    #[serde(default)]
+    pub artists: Vec<ArtistItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Album;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_artist_detail_response() {
+        let response: ArtistDetailResponse = parse_response(include_bytes!("../../tests/fixtures/artist_detail.json")).unwrap();
+        let artist = Artist::from(response.data.artist);
+
+        assert_eq!(artist.name, "周杰伦");
+        assert_eq!(artist.id, 6452);
+        assert_eq!(artist.albums_count, 42);
+        assert_eq!(artist.songs_count, 568);
+        assert!(artist.brief_desc.starts_with("周杰伦"));
+
+        // 歌手 id 不是歌单 id
+        assert_eq!(artist.as_songlist().songlist_id(), None);
+    }
+
+    #[test]
+    fn parse_artist_album_response() {
+        let response: ArtistAlbumResponse = parse_response(include_bytes!("../../tests/fixtures/artist_album.json")).unwrap();
+        assert!(response.more);
+
+        let albums: Vec<Album> = response.hot_albums.into_iter().map(Album::from).collect();
+        assert_eq!(albums.len(), 2);
+        assert_eq!(albums[1].name, "范特西");
+        assert_eq!(albums[1].artist_id, 6452);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::model::Comment;
+use serde::Deserialize;
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentUserItem {
+    #[serde(default)]
+    pub user_id: u64,
+    pub nickname: Option<String>,
+}
+
+/// 评论（`comments` 或 `hotComments` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentItem {
+    pub comment_id: u64,
+    pub user: Option<CommentUserItem>,
+    pub content: Option<String>,
+    #[serde(default)]
+    pub time: i64,
+    #[serde(default)]
+    pub liked_count: u64,
+    #[serde(default)]
+    pub liked: bool,
+}
+
+impl From<CommentItem> for Comment {
+    fn from(item: CommentItem) -> Self {
+        let (user_name, user_id) = match item.user {
+            Some(user) => (user.nickname.unwrap_or_else(|| String::from("Unknown")), user.user_id),
+            None => (String::from("Unknown"), 0),
+        };
+
+        Comment {
+            id: item.comment_id,
+            user_name,
+            user_id,
+            content: item.content.unwrap_or_default(),
+            liked_count: item.liked_count,
+            liked: item.liked,
+            time: item.time,
+        }
+    }
+}
+
+/// `/comment/music`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct CommentMusicResponse {
+    #[serde(default)]
+    pub comments: Vec<CommentItem>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub more: bool,
+}
+
+/// `/comment/hot`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentHotResponse {
+    #[serde(default)]
+    pub hot_comments: Vec<CommentItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_comment_music_response() {
+        let response: CommentMusicResponse = parse_response(include_bytes!("../../tests/fixtures/comment_music.json")).unwrap();
+        assert_eq!(response.total, 52013);
+        assert!(response.more);
+
+        let comments: Vec<Comment> = response.comments.into_iter().map(Comment::from).collect();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].id, 7001234567);
+        assert_eq!(comments[0].user_name, "晚风与你");
+        assert_eq!(comments[0].user_id, 100001);
+        assert_eq!(comments[0].content, "第一次听是在高中的晚自习\n现在已经工作五年了");
+        assert_eq!(comments[0].liked_count, 128);
+        assert!(!comments[0].liked);
+        assert!(comments[1].liked);
+        assert_eq!(comments[1].time, 1728907200000);
+    }
+
+    #[test]
+    fn parse_comment_hot_response() {
+        let response: CommentHotResponse = parse_response(include_bytes!("../../tests/fixtures/comment_hot.json")).unwrap();
+
+        let comments: Vec<Comment> = response.hot_comments.into_iter().map(Comment::from).collect();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].user_name, "夏天的风");
+        assert_eq!(comments[0].liked_count, 1200345);
+    }
+}
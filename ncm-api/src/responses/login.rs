@@ -1,3 +1,4 @@
+use crate::model::Account;
 use serde::Deserialize;
 
 #[allow(unused)]
@@ -28,3 +29,58 @@ pub struct QrCheckResponse {
     pub message: String,
     pub cookie: String,
 }
+
+/// `/login/status` ，未登录时 `profile` 为 null
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct LoginStatusResponse {
+    pub data: LoginStatusData,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct LoginStatusData {
+    pub profile: Option<ProfileItem>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileItem {
+    pub user_id: u64,
+    pub nickname: String,
+    #[serde(default)]
+    pub vip_type: i64,
+}
+
+impl From<ProfileItem> for Account {
+    fn from(item: ProfileItem) -> Self {
+        Account {
+            user_id: item.user_id,
+            nickname: item.nickname,
+            vip_type: item.vip_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_login_status_response() {
+        let response: LoginStatusResponse = serde_json::from_slice(include_bytes!("../../tests/fixtures/login_status.json")).unwrap();
+        let account = Account::from(response.data.profile.unwrap());
+
+        assert_eq!(account.user_id, 123456789);
+        assert_eq!(account.nickname, "测试用户");
+        assert_eq!(account.vip_type, 11);
+    }
+
+    #[test]
+    fn parse_login_status_response_without_login() {
+        let response: LoginStatusResponse = serde_json::from_str(r#"{"data": {"code": 200, "account": null, "profile": null}}"#).unwrap();
+
+        assert!(response.data.profile.is_none());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::model::{DjRadio, Program};
+use serde::Deserialize;
+
+/// 主播
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DjItem {
+    #[serde(default)]
+    pub user_id: u64,
+    pub nickname: Option<String>,
+}
+
+/// 电台概要（`djRadios` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DjRadioItem {
+    pub name: String,
+    pub id: u64,
+    pub dj: Option<DjItem>,
+    pub category: Option<String>,
+    #[serde(default)]
+    pub program_count: usize,
+    pub last_program_name: Option<String>,
+    pub pic_url: Option<String>,
+}
+
+impl From<DjRadioItem> for DjRadio {
+    fn from(item: DjRadioItem) -> Self {
+        DjRadio {
+            name: item.name,
+            id: item.id,
+            dj_name: item.dj.and_then(|dj| dj.nickname).unwrap_or_else(|| String::from("Unknown")),
+            category: item.category.unwrap_or_default(),
+            programs_count: item.program_count,
+            last_program_name: item.last_program_name.unwrap_or_default(),
+            cover_url: item.pic_url,
+        }
+    }
+}
+
+/// `/dj/sublist`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DjSublistResponse {
+    #[serde(default)]
+    pub dj_radios: Vec<DjRadioItem>,
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// 节目所属电台
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ProgramRadioItem {
+    pub id: u64,
+    pub name: Option<String>,
+}
+
+/// 节目音频
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ProgramMainSongItem {
+    pub id: u64,
+    #[serde(default)]
+    pub duration: Option<u64>,
+}
+
+/// 节目（`programs` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub main_track_id: Option<u64>,
+    pub main_song: Option<ProgramMainSongItem>,
+    pub radio: Option<ProgramRadioItem>,
+    pub dj: Option<DjItem>,
+    #[serde(default)]
+    pub serial_num: u32,
+    #[serde(default)]
+    pub duration: Option<u64>,
+    #[serde(default)]
+    pub create_time: Option<i64>,
+    pub description: Option<String>,
+    pub cover_url: Option<String>,
+}
+
+impl From<ProgramItem> for Program {
+    fn from(item: ProgramItem) -> Self {
+        let main_song_duration = item.main_song.as_ref().and_then(|main_song| main_song.duration);
+        let main_song_id = item.main_track_id.or(item.main_song.map(|main_song| main_song.id)).unwrap_or(0);
+        let (radio_name, radio_id) = match item.radio {
+            Some(radio) => (radio.name.unwrap_or_default(), radio.id),
+            None => (String::new(), 0),
+        };
+
+        Program {
+            name: item.name,
+            id: item.id,
+            main_song_id,
+            radio_name,
+            radio_id,
+            dj_name: item.dj.and_then(|dj| dj.nickname).unwrap_or_else(|| String::from("Unknown")),
+            serial_num: item.serial_num,
+            duration: item.duration.or(main_song_duration).unwrap_or(0),
+            create_time: item.create_time.unwrap_or(0),
+            description: item.description.unwrap_or_default(),
+            cover_url: item.cover_url,
+        }
+    }
+}
+
+/// `/dj/program`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct DjProgramResponse {
+    #[serde(default)]
+    pub programs: Vec<ProgramItem>,
+    #[serde(default)]
+    pub more: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Song;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_dj_sublist_response() {
+        let response: DjSublistResponse = parse_response(include_bytes!("../../tests/fixtures/dj_sublist.json")).unwrap();
+        assert!(!response.has_more);
+
+        let dj_radios: Vec<DjRadio> = response.dj_radios.into_iter().map(DjRadio::from).collect();
+        assert_eq!(dj_radios.len(), 2);
+        assert_eq!(dj_radios[0].name, "深夜电台");
+        assert_eq!(dj_radios[0].id, 794062371);
+        assert_eq!(dj_radios[0].dj_name, "声音旅人");
+        assert_eq!(dj_radios[0].programs_count, 312);
+        assert_eq!(dj_radios[0].last_program_name, "第312期 | 写给十年后的自己");
+        assert_eq!(dj_radios[1].cover_url, None);
+        assert_eq!(dj_radios[1].last_program_name, "");
+    }
+
+    #[test]
+    fn parse_dj_program_response() {
+        let response: DjProgramResponse = parse_response(include_bytes!("../../tests/fixtures/dj_program.json")).unwrap();
+        assert!(response.more);
+
+        let programs: Vec<Program> = response.programs.into_iter().map(Program::from).collect();
+        assert_eq!(programs.len(), 2);
+        assert_eq!(programs[0].id, 2530123456);
+        assert_eq!(programs[0].main_song_id, 2061234567);
+        assert_eq!(programs[0].radio_name, "深夜电台");
+        assert_eq!(programs[0].serial_num, 312);
+        assert_eq!(programs[0].duration, 4321000);
+        assert_eq!(programs[1].description, "");
+
+        // 作为歌曲播放时使用节目音频的 id
+        let song: Song = programs[1].as_song();
+        assert_eq!(song.id, 2061234000);
+        assert_eq!(song.singer(), "声音旅人");
+        assert_eq!(song.album, "深夜电台");
+        assert_eq!(song.duration, 1805000);
+    }
+}
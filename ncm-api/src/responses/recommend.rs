@@ -0,0 +1,173 @@
+use crate::model::{Artist, Song, SongAvailability};
+use crate::responses::song::{SongAlbumItem, SongArtistItem, SongItem};
+use crate::responses::songlist::PlaylistItem;
+use serde::Deserialize;
+
+/// `/recommend/songs`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct RecommendSongsResponse {
+    pub data: RecommendSongsData,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendSongsData {
+    #[serde(default)]
+    pub daily_songs: Vec<SongItem>,
+}
+
+/// `/recommend/resource`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct RecommendResourceResponse {
+    #[serde(default)]
+    pub recommend: Vec<PlaylistItem>,
+}
+
+/// `/personal_fm`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct PersonalFmResponse {
+    #[serde(default)]
+    pub data: Vec<PersonalFmSongItem>,
+}
+
+/// 私人 FM 中的歌曲（旧版歌曲格式，字段名与 `SongItem` 不同）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalFmSongItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub artists: Vec<SongArtistItem>,
+    #[serde(default)]
+    pub alias: Vec<String>,
+    #[serde(default)]
+    pub trans_names: Option<Vec<String>>,
+    #[serde(default)]
+    pub album: Option<SongAlbumItem>,
+    #[serde(default)]
+    pub duration: Option<u64>,
+    #[serde(default)]
+    pub fee: Option<i64>,
+    #[serde(default)]
+    pub popularity: Option<f64>,
+    #[serde(default)]
+    pub no: Option<u32>,
+}
+
+impl From<PersonalFmSongItem> for Song {
+    fn from(item: PersonalFmSongItem) -> Self {
+        let (album, album_id, cover_url) = match item.album {
+            Some(album) => (album.name.unwrap_or_else(|| String::from("Unknown")), album.id, album.pic_url),
+            None => (String::from("Unknown"), 0, None),
+        };
+
+        Song {
+            name: item.name,
+            id: item.id,
+            artists: item.artists.into_iter().map(Artist::from).collect(),
+            alias: item.alias,
+            trans_name: item.trans_names.and_then(|trans_names| trans_names.into_iter().next()),
+            album,
+            album_id,
+            cover_url,
+            duration: item.duration.unwrap_or(0),
+            fee: item.fee.unwrap_or(0),
+            popularity: item.popularity.unwrap_or(0.0) as u32,
+            track_no: item.no.unwrap_or(0),
+            song_url: None,
+            quality_level: String::new(),
+            availability: SongAvailability::Unknown,
+        }
+    }
+}
+
+/// `/playmode/intelligence/list`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct IntelligenceListResponse {
+    #[serde(default)]
+    pub data: Vec<IntelligenceItem>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IntelligenceItem {
+    pub id: u64,
+    #[serde(default)]
+    pub recommended: bool,
+    pub song_info: SongItem,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Songlist;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_recommend_songs_response() {
+        let response: RecommendSongsResponse = parse_response(include_bytes!("../../tests/fixtures/recommend_songs.json")).unwrap();
+
+        let songs: Vec<Song> = response.data.daily_songs.into_iter().map(Song::from).collect();
+        assert_eq!(songs.len(), 2);
+        assert_eq!(songs[0].name, "晴天");
+        assert_eq!(songs[0].singer(), "周杰伦");
+        assert_eq!(songs[0].album, "叶惠美");
+        assert_eq!(songs[1].id, 254574);
+        assert_eq!(songs[1].duration, 341000);
+    }
+
+    #[test]
+    fn parse_recommend_resource_response() {
+        let response: RecommendResourceResponse = parse_response(include_bytes!("../../tests/fixtures/recommend_resource.json")).unwrap();
+
+        let songlists: Vec<Songlist> = response.recommend.into_iter().map(Songlist::from).collect();
+        assert_eq!(songlists.len(), 2);
+        assert_eq!(songlists[0].name, "[华语私人订制] 最懂你的华语推荐 每日更新35首");
+        assert_eq!(songlists[0].id, 2819914042);
+        assert_eq!(songlists[0].songs_count, 35);
+        assert_eq!(songlists[0].creator, "网易云音乐");
+        assert_eq!(songlists[1].creator_id, 987654321);
+        assert!(songlists[1].songs.is_empty());
+    }
+
+    #[test]
+    fn parse_intelligence_list_response() {
+        let response: IntelligenceListResponse = parse_response(include_bytes!("../../tests/fixtures/playmode_intelligence_list.json")).unwrap();
+        assert_eq!(response.data.len(), 3);
+        assert!(!response.data[0].recommended);
+        assert!(response.data[1].recommended);
+
+        let songs: Vec<Song> = response.data.into_iter().map(|item| Song::from(item.song_info)).collect();
+        assert_eq!(songs[0].id, 186016);
+        assert_eq!(songs[1].name, "告白气球");
+        assert_eq!(songs[2].singer(), "王菲");
+        assert_eq!(songs[2].cover_url, None);
+    }
+
+    #[test]
+    fn parse_personal_fm_response() {
+        let response: PersonalFmResponse = parse_response(include_bytes!("../../tests/fixtures/personal_fm.json")).unwrap();
+
+        let songs: Vec<Song> = response.data.into_iter().map(Song::from).collect();
+        assert_eq!(songs.len(), 2);
+        assert_eq!(songs[0].name, "七里香");
+        assert_eq!(songs[0].id, 186001);
+        assert_eq!(songs[0].singer(), "周杰伦");
+        assert_eq!(songs[0].album, "七里香");
+        assert_eq!(songs[0].album_id, 18903);
+        assert_eq!(songs[0].duration, 299000);
+        assert_eq!(songs[0].trans_name, None);
+
+        assert_eq!(songs[1].cover_url, None);
+        assert_eq!(songs[1].alias, vec![String::from("Remastered")]);
+        assert_eq!(songs[1].full_name(), "Imagine (想象)");
+        assert_eq!(songs[1].fee, 8);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::responses::album::AlbumItem;
+use crate::responses::artist::ArtistItem;
+use crate::responses::song::SongItem;
+use crate::responses::songlist::PlaylistItem;
+use serde::Deserialize;
+
+/// `/cloudsearch`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct CloudsearchResponse {
+    /// 无结果时可能缺失
+    #[serde(default)]
+    pub result: CloudsearchResult,
+}
+
+/// 不同搜索类型只会返回对应的一组字段
+#[allow(unused)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CloudsearchResult {
+    pub songs: Vec<SongItem>,
+    pub song_count: usize,
+    pub albums: Vec<AlbumItem>,
+    pub album_count: usize,
+    pub artists: Vec<ArtistItem>,
+    pub artist_count: usize,
+    pub playlists: Vec<PlaylistItem>,
+    pub playlist_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Song;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_cloudsearch_song_response() {
+        let response: CloudsearchResponse = parse_response(include_bytes!("../../tests/fixtures/cloudsearch_song.json")).unwrap();
+        assert_eq!(response.result.song_count, 300);
+        assert!(response.result.albums.is_empty());
+
+        let songs: Vec<Song> = response.result.songs.into_iter().map(Song::from).collect();
+        assert_eq!(songs.len(), 1);
+        assert_eq!(songs[0].name, "晴天");
+        assert_eq!(songs[0].singer(), "周杰伦");
+    }
+
+    #[test]
+    fn parse_cloudsearch_empty_response() {
+        let response: CloudsearchResponse = parse_response(br#"{"code": 200, "result": {}}"#).unwrap();
+        assert_eq!(response.result.playlist_count, 0);
+        assert!(response.result.playlists.is_empty());
+
+        let response: CloudsearchResponse = parse_response(br#"{"code": 200}"#).unwrap();
+        assert!(response.result.songs.is_empty());
+    }
+}
@@ -0,0 +1,354 @@
+use crate::model::{Artist, Quality, Song, SongAvailability, SongUrl};
+use chrono::Utc;
+use serde::Deserialize;
+
+/// 歌曲详情（`songs` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SongItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub ar: Vec<SongArtistItem>,
+    #[serde(default)]
+    pub alia: Vec<String>,
+    #[serde(default)]
+    pub tns: Option<Vec<String>>,
+    #[serde(default)]
+    pub al: Option<SongAlbumItem>,
+    #[serde(default)]
+    pub dt: Option<u64>,
+    #[serde(default)]
+    pub fee: Option<i64>,
+    #[serde(default)]
+    pub pop: Option<f64>,
+    #[serde(default)]
+    pub no: Option<u32>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SongArtistItem {
+    #[serde(default)]
+    pub id: u64,
+    pub name: Option<String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SongAlbumItem {
+    #[serde(default)]
+    pub id: u64,
+    pub name: Option<String>,
+    pub pic_url: Option<String>,
+}
+
+impl From<SongArtistItem> for Artist {
+    /// 只含歌手名和 id
+    fn from(item: SongArtistItem) -> Self {
+        Artist {
+            name: item.name.unwrap_or_else(|| String::from("Unknown")),
+            id: item.id,
+            albums_count: 0,
+            songs_count: 0,
+            brief_desc: String::new(),
+            top_songs: Vec::new(),
+        }
+    }
+}
+
+impl From<SongItem> for Song {
+    fn from(item: SongItem) -> Self {
+        let (album, album_id, cover_url) = match item.al {
+            Some(album) => (album.name.unwrap_or_else(|| String::from("Unknown")), album.id, album.pic_url),
+            None => (String::from("Unknown"), 0, None),
+        };
+
+        Song {
+            name: item.name,
+            id: item.id,
+            artists: item.ar.into_iter().map(Artist::from).collect(),
+            alias: item.alia,
+            trans_name: item.tns.and_then(|tns| tns.into_iter().next()),
+            album,
+            album_id,
+            cover_url,
+            duration: item.dt.unwrap_or(0),
+            fee: item.fee.unwrap_or(0),
+            popularity: item.pop.unwrap_or(0.0) as u32,
+            track_no: item.no.unwrap_or(0),
+            song_url: None,
+            quality_level: String::new(),
+            availability: SongAvailability::Unknown,
+        }
+    }
+}
+
+/// `/likelist`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct LikelistResponse {
+    #[serde(default)]
+    pub ids: Vec<u64>,
+}
+
+/// `/song/detail`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SongDetailResponse {
+    #[serde(default)]
+    pub songs: Vec<SongItem>,
+    #[serde(default)]
+    pub privileges: Vec<PrivilegeItem>,
+}
+
+/// 歌曲对于登录用户的播放权限（`privileges` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct PrivilegeItem {
+    pub id: u64,
+    #[serde(default)]
+    pub fee: i64,
+    /// 状态，小于 0 时歌曲已下架
+    #[serde(default)]
+    pub st: i64,
+    /// 可播放的最高码率，为 0 时不能完整播放
+    #[serde(default)]
+    pub pl: i64,
+}
+
+impl PrivilegeItem {
+    pub fn availability(&self) -> SongAvailability {
+        if self.st < 0 {
+            SongAvailability::NoCopyright
+        } else if self.pl > 0 {
+            SongAvailability::Available
+        } else if matches!(self.fee, 1 | 4) {
+            SongAvailability::VipOnly
+        } else {
+            SongAvailability::NoCopyright
+        }
+    }
+}
+
+/// `/song/url/v1`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SongUrlResponse {
+    #[serde(default)]
+    pub data: Vec<SongUrlItem>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct SongUrlItem {
+    pub id: u64,
+    pub url: Option<String>,
+    pub level: Option<String>,
+    /// 单曲的状态码，200 为可获取
+    #[serde(default)]
+    pub code: i64,
+    /// 链接有效期（s）
+    pub expi: Option<i64>,
+    #[serde(default)]
+    pub fee: Option<i64>,
+    /// 只能试听时为试听片段的起止时间
+    #[serde(default, rename = "freeTrialInfo")]
+    pub free_trial_info: Option<serde_json::Value>,
+}
+
+impl SongUrlItem {
+    /// 未返回有效期时按 20 分钟计
+    const DEFAULT_EXPI: i64 = 1200;
+
+    pub fn is_available(&self) -> bool {
+        self.code == 200 && self.url.is_some()
+    }
+
+    pub fn availability(&self) -> SongAvailability {
+        if self.is_available() {
+            if self.free_trial_info.is_some() {
+                SongAvailability::TrialOnly
+            } else {
+                SongAvailability::Available
+            }
+        } else if matches!(self.fee, Some(1 | 4)) {
+            SongAvailability::VipOnly
+        } else {
+            SongAvailability::NoCopyright
+        }
+    }
+}
+
+impl From<SongUrlItem> for SongUrl {
+    fn from(item: SongUrlItem) -> Self {
+        let availability = item.availability();
+        let url = if item.is_available() { item.url } else { None };
+        // 服务端可能返回低于请求的音质，以实际返回的为准
+        let quality_level = match item.level {
+            Some(level) => match level.parse::<Quality>() {
+                Ok(quality) => quality.label().to_string(),
+                Err(_) => level,
+            },
+            None => String::new(),
+        };
+
+        SongUrl {
+            song_id: item.id,
+            url,
+            quality_level,
+            availability,
+            expires_at: Utc::now().timestamp_millis() + item.expi.unwrap_or(SongUrlItem::DEFAULT_EXPI) * 1000,
+        }
+    }
+}
+
+/// `/record/recent/song`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct RecentSongResponse {
+    pub data: RecentSongData,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct RecentSongData {
+    #[serde(default)]
+    pub list: Vec<RecentSongItem>,
+}
+
+/// 播放记录中的单项，已下架的歌曲 `data` 为 null
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentSongItem {
+    #[serde(default)]
+    pub play_time: i64,
+    pub data: Option<SongItem>,
+}
+
+/// `/lyric`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct LyricResponse {
+    pub lrc: Option<LyricItem>,
+    pub tlyric: Option<LyricItem>,
+    pub romalrc: Option<LyricItem>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct LyricItem {
+    pub lyric: Option<String>,
+}
+
+impl LyricResponse {
+    /// 原文、翻译、罗马音歌词文本（缺失时为空）
+    pub fn lyric_texts(self) -> (String, String, String) {
+        let text = |item: Option<LyricItem>| item.and_then(|item| item.lyric).unwrap_or_default();
+
+        (text(self.lrc), text(self.tlyric), text(self.romalrc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_song_url_response() {
+        let response: SongUrlResponse = parse_response(include_bytes!("../../tests/fixtures/song_url.json")).unwrap();
+
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].id, 1901371647);
+        assert_eq!(response.data[0].url.as_deref(), Some("http://m801.music.126.net/example.flac"));
+        assert_eq!(response.data[0].level.as_deref(), Some("lossless"));
+        assert!(response.data[0].is_available());
+
+        let song_url = SongUrl::from(response.data.into_iter().next().unwrap());
+        assert_eq!(song_url.quality_level, "无损");
+        assert!(song_url.is_available());
+        assert!(!song_url.is_expired());
+    }
+
+    #[test]
+    fn parse_batch_song_url_response() {
+        let response: SongUrlResponse = parse_response(include_bytes!("../../tests/fixtures/song_url_batch.json")).unwrap();
+        assert_eq!(response.data.len(), 4);
+
+        let song_urls: Vec<SongUrl> = response.data.into_iter().map(SongUrl::from).collect();
+        assert_eq!(song_urls[0].song_id, 186016);
+        assert!(song_urls[0].is_available());
+        assert_eq!(song_urls[0].quality_level, "极高");
+        assert_eq!(song_urls[0].availability, SongAvailability::Available);
+        // 无版权的歌曲
+        assert_eq!(song_urls[1].song_id, 5257138);
+        assert!(!song_urls[1].is_available());
+        assert_eq!(song_urls[1].availability, SongAvailability::NoCopyright);
+        // 只能试听的 VIP 歌曲
+        assert!(song_urls[2].is_available());
+        assert_eq!(song_urls[2].availability, SongAvailability::TrialOnly);
+        // 无法获取的 VIP 歌曲
+        assert!(!song_urls[3].is_available());
+        assert_eq!(song_urls[3].availability, SongAvailability::VipOnly);
+    }
+
+    #[test]
+    fn parse_song_detail_privileges() {
+        let response: SongDetailResponse = parse_response(include_bytes!("../../tests/fixtures/song_detail.json")).unwrap();
+        assert_eq!(response.songs.len(), 1);
+        assert_eq!(response.privileges.len(), 4);
+
+        let songs_availability: Vec<(u64, SongAvailability)> = response.privileges.iter().map(|privilege| (privilege.id, privilege.availability())).collect();
+        assert_eq!(songs_availability[0], (186016, SongAvailability::Available));
+        // 已下架的歌曲
+        assert_eq!(songs_availability[1], (5257138, SongAvailability::NoCopyright));
+        // 无法播放的 VIP 歌曲和付费专辑
+        assert_eq!(songs_availability[2], (1330348068, SongAvailability::VipOnly));
+        assert_eq!(songs_availability[3], (1824020871, SongAvailability::VipOnly));
+    }
+
+    #[test]
+    fn parse_lyric_response() {
+        let response: LyricResponse = parse_response(include_bytes!("../../tests/fixtures/lyric.json")).unwrap();
+        let (lyric, trans_lyric, roman_lyric) = response.lyric_texts();
+
+        assert!(lyric.starts_with("[00:00.000]"));
+        assert!(trans_lyric.contains("[00:12.340]"));
+        assert!(roman_lyric.is_empty());
+    }
+
+    #[test]
+    fn parse_likelist_response() {
+        let likelist: LikelistResponse = parse_response(br#"{"code": 200, "ids": [1, 2, 3], "checkPoint": 0}"#).unwrap();
+        assert_eq!(likelist.ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_recent_song_response() {
+        let response: RecentSongResponse = parse_response(include_bytes!("../../tests/fixtures/record_recent_song.json")).unwrap();
+        assert_eq!(response.data.list.len(), 3);
+        assert_eq!(response.data.list[0].play_time, 1728993600000);
+
+        let songs: Vec<Song> = response.data.list.into_iter().filter_map(|item| item.data).map(Song::from).collect();
+        assert_eq!(songs.len(), 2);
+        assert_eq!(songs[0].name, "晴天");
+        assert_eq!(songs[0].singer(), "周杰伦");
+        assert_eq!(songs[1].id, 254574);
+        assert_eq!(songs[1].album, "我等你");
+    }
+
+    #[test]
+    fn song_item_with_missing_fields() {
+        let item: SongItem = serde_json::from_str(r#"{"name": "无名", "id": 1, "ar": [], "al": null}"#).unwrap();
+        let song = Song::from(item);
+
+        assert!(song.artists.is_empty());
+        assert_eq!(song.singer(), "Unknown");
+        assert_eq!(song.trans_name, None);
+        assert_eq!(song.album_id, 0);
+        assert_eq!(song.duration, 0);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::error::{check_response_code, NcmResult};
+use crate::model::{Songlist, SonglistSource};
+use crate::responses::song::SongItem;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// 歌单概要（`playlist` / `playlists` 数组中的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub track_count: Option<usize>,
+    pub creator: Option<PlaylistCreatorItem>,
+    #[serde(default)]
+    pub subscribed: bool,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistCreatorItem {
+    #[serde(default)]
+    pub user_id: u64,
+    pub nickname: Option<String>,
+}
+
+impl From<PlaylistItem> for Songlist {
+    /// 只含歌单概要，不含歌单内的歌曲
+    fn from(item: PlaylistItem) -> Self {
+        let (creator, creator_id) = match item.creator {
+            Some(creator) => (creator.nickname.unwrap_or_default(), creator.user_id),
+            None => (String::new(), 0),
+        };
+
+        Songlist {
+            name: item.name,
+            id: item.id,
+            songs_count: item.track_count.unwrap_or(0),
+            creator,
+            creator_id,
+            subscribed: item.subscribed,
+            songs: Vec::new(),
+            source: SonglistSource::Songlist,
+        }
+    }
+}
+
+/// `/user/playlist`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct UserPlaylistResponse {
+    #[serde(default)]
+    pub more: bool,
+    #[serde(default)]
+    pub playlist: Vec<PlaylistItem>,
+}
+
+/// 官方排行榜（`/toplist` 中 `list` 数组的单项）
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ToplistItem {
+    pub name: String,
+    pub id: u64,
+    #[serde(default)]
+    pub track_count: Option<usize>,
+    pub update_frequency: Option<String>,
+}
+
+impl From<ToplistItem> for Songlist {
+    /// 排行榜没有创建者，以更新频率代替
+    fn from(item: ToplistItem) -> Self {
+        Songlist {
+            name: item.name,
+            id: item.id,
+            songs_count: item.track_count.unwrap_or(0),
+            creator: item.update_frequency.unwrap_or_default(),
+            creator_id: 0,
+            subscribed: false,
+            songs: Vec::new(),
+            source: SonglistSource::Songlist,
+        }
+    }
+}
+
+/// `/toplist`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct ToplistResponse {
+    #[serde(default)]
+    pub list: Vec<ToplistItem>,
+}
+
+/// `/playlist/track/all`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct PlaylistTrackAllResponse {
+    #[serde(default)]
+    pub songs: Vec<SongItem>,
+}
+
+/// `/playlist/create`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct PlaylistCreateResponse {
+    pub id: u64,
+}
+
+/// 检查 `/playlist/tracks` 的响应
+///
+/// 成功时状态码包在 `body` 中（`{"status": 200, "body": {"code": 200, ...}}`），失败时直接在顶层
+pub fn check_playlist_tracks_response(bytes: &[u8]) -> NcmResult<()> {
+    let v: Value = serde_json::from_slice(bytes)?;
+
+    match v.get("body") {
+        Some(body) if v.get("code").is_none() => check_response_code(body),
+        _ => check_response_code(&v),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NcmError;
+    use crate::model::Song;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_user_playlist_response() {
+        let response: UserPlaylistResponse = parse_response(include_bytes!("../../tests/fixtures/user_playlist.json")).unwrap();
+        assert!(!response.more);
+
+        let songlists: Vec<Songlist> = response.playlist.into_iter().map(Songlist::from).collect();
+        assert_eq!(songlists.len(), 2);
+        assert_eq!(songlists[0].name, "测试用户喜欢的音乐");
+        assert_eq!(songlists[0].id, 2829883282);
+        assert_eq!(songlists[0].songs_count, 1024);
+        assert_eq!(songlists[0].creator, "测试用户");
+        assert_eq!(songlists[0].creator_id, 123456789);
+        assert!(!songlists[0].subscribed);
+        assert_eq!(songlists[0].songlist_id(), Some(2829883282));
+        assert_eq!(songlists[1].creator, "另一位用户");
+        assert_eq!(songlists[1].creator_id, 987654321);
+        assert!(songlists[1].subscribed);
+    }
+
+    #[test]
+    fn parse_toplist_response() {
+        let response: ToplistResponse = parse_response(include_bytes!("../../tests/fixtures/toplist.json")).unwrap();
+
+        let toplists: Vec<Songlist> = response.list.into_iter().map(Songlist::from).collect();
+        assert_eq!(toplists.len(), 3);
+        assert_eq!(toplists[0].name, "飙升榜");
+        assert_eq!(toplists[0].id, 19723756);
+        assert_eq!(toplists[0].songs_count, 100);
+        assert_eq!(toplists[0].creator, "每天更新");
+        assert_eq!(toplists[1].name, "新歌榜");
+        assert_eq!(toplists[2].creator, "");
+        assert_eq!(toplists[2].creator_id, 0);
+    }
+
+    #[test]
+    fn parse_playlist_track_all_response() {
+        let response: PlaylistTrackAllResponse = parse_response(include_bytes!("../../tests/fixtures/playlist_track_all.json")).unwrap();
+
+        let songs: Vec<Song> = response.songs.into_iter().map(Song::from).collect();
+        assert_eq!(songs.len(), 3);
+        assert_eq!(songs[0].name, "晴天");
+        assert_eq!(songs[0].id, 186016);
+        assert_eq!(songs[0].singer(), "周杰伦");
+        assert_eq!(songs[0].artists[0].id, 6452);
+        assert_eq!(songs[0].album, "叶惠美");
+        assert_eq!(songs[0].album_id, 18905);
+        assert_eq!(songs[0].cover_url.as_deref(), Some("http://p1.music.126.net/example/album.jpg"));
+        assert_eq!(songs[0].duration, 269000);
+        assert!(songs[0].is_vip_only());
+        assert_eq!(songs[0].popularity, 100);
+        assert_eq!(songs[0].track_no, 3);
+
+        assert_eq!(songs[1].singer(), "Unknown");
+        assert_eq!(songs[1].cover_url, None);
+        assert!(!songs[1].is_vip_only());
+
+        assert_eq!(songs[2].artists.len(), 2);
+        assert_eq!(songs[2].singer(), "周杰伦/杨瑞代");
+        assert_eq!(songs[2].artists[1].id, 1049179);
+        assert_eq!(songs[2].alias, vec![String::from("电影《不能说的秘密》插曲")]);
+        assert_eq!(songs[2].trans_name.as_deref(), Some("Lovers in the Past"));
+        assert_eq!(songs[2].full_name(), "前世情人 (Lovers in the Past)");
+        assert_eq!(songs[2].fee, 8);
+    }
+
+    #[test]
+    fn parse_playlist_create_response() {
+        let response: PlaylistCreateResponse = parse_response(include_bytes!("../../tests/fixtures/playlist_create.json")).unwrap();
+        assert_eq!(response.id, 13579246801);
+    }
+
+    #[test]
+    fn check_playlist_tracks_response_in_both_shapes() {
+        assert!(check_playlist_tracks_response(br#"{"status": 200, "body": {"trackIds": "[186016]", "count": 3, "cloudCount": 0, "code": 200}}"#).is_ok());
+        assert_eq!(
+            check_playlist_tracks_response(r#"{"code": 502, "message": "歌单内歌曲重复"}"#.as_bytes()).unwrap_err(),
+            NcmError::ApiCode {
+                code: 502,
+                message: String::from("歌单内歌曲重复")
+            }
+        );
+        assert_eq!(
+            check_playlist_tracks_response(r#"{"status": 200, "body": {"code": 401, "message": "无权限"}}"#.as_bytes()).unwrap_err(),
+            NcmError::ApiCode {
+                code: 401,
+                message: String::from("无权限")
+            }
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::model::{RankedSong, Song, UserLevel, UserProfile};
+use crate::responses::song::SongItem;
+use serde::Deserialize;
+
+/// `/user/detail`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDetailResponse {
+    #[serde(default)]
+    pub level: u32,
+    #[serde(default)]
+    pub listen_songs: u64,
+    #[serde(default)]
+    pub create_days: u64,
+    pub profile: UserDetailProfileItem,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDetailProfileItem {
+    pub user_id: u64,
+    pub nickname: String,
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub vip_type: i64,
+    #[serde(default)]
+    pub follows: u64,
+    #[serde(default)]
+    pub followeds: u64,
+    #[serde(default)]
+    pub playlist_count: u64,
+}
+
+impl From<UserDetailResponse> for UserProfile {
+    fn from(response: UserDetailResponse) -> Self {
+        UserProfile {
+            user_id: response.profile.user_id,
+            nickname: response.profile.nickname,
+            signature: response.profile.signature.unwrap_or_default(),
+            vip_type: response.profile.vip_type,
+            level: response.level,
+            listen_songs: response.listen_songs,
+            follows: response.profile.follows,
+            followeds: response.profile.followeds,
+            playlist_count: response.profile.playlist_count,
+            create_days: response.create_days,
+        }
+    }
+}
+
+/// `/user/level`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+pub struct UserLevelResponse {
+    pub data: UserLevelItem,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLevelItem {
+    #[serde(default)]
+    pub level: u32,
+    #[serde(default)]
+    pub progress: f64,
+    #[serde(default)]
+    pub now_play_count: u64,
+    #[serde(default)]
+    pub next_play_count: u64,
+    #[serde(default)]
+    pub now_login_count: u64,
+    #[serde(default)]
+    pub next_login_count: u64,
+}
+
+impl From<UserLevelItem> for UserLevel {
+    fn from(item: UserLevelItem) -> Self {
+        UserLevel {
+            level: item.level,
+            progress: item.progress,
+            now_play_count: item.now_play_count,
+            next_play_count: item.next_play_count,
+            now_login_count: item.now_login_count,
+            next_login_count: item.next_login_count,
+        }
+    }
+}
+
+/// `/user/record` ，`type=1` 时只有 `weekData` ，`type=0` 时只有 `allData`
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRecordResponse {
+    #[serde(default)]
+    pub week_data: Vec<UserRecordItem>,
+    #[serde(default)]
+    pub all_data: Vec<UserRecordItem>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRecordItem {
+    #[serde(default)]
+    pub play_count: u64,
+    #[serde(default)]
+    pub score: u64,
+    pub song: SongItem,
+}
+
+impl From<UserRecordItem> for RankedSong {
+    fn from(item: UserRecordItem) -> Self {
+        RankedSong {
+            song: Song::from(item.song),
+            play_count: item.play_count,
+            score: item.score,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::parse_response;
+
+    #[test]
+    fn parse_user_detail_response() {
+        let response: UserDetailResponse = parse_response(include_bytes!("../../tests/fixtures/user_detail.json")).unwrap();
+        let profile = UserProfile::from(response);
+
+        assert_eq!(profile.user_id, 123456789);
+        assert_eq!(profile.nickname, "测试用户");
+        assert_eq!(profile.signature, "");
+        assert_eq!(profile.vip_type, 11);
+        assert_eq!(profile.level, 9);
+        assert_eq!(profile.listen_songs, 12345);
+        assert_eq!(profile.follows, 42);
+        assert_eq!(profile.followeds, 7);
+        assert_eq!(profile.playlist_count, 15);
+        assert_eq!(profile.create_days, 2100);
+    }
+
+    #[test]
+    fn parse_user_level_response() {
+        let response: UserLevelResponse = parse_response(include_bytes!("../../tests/fixtures/user_level.json")).unwrap();
+        let level = UserLevel::from(response.data);
+
+        assert_eq!(level.level, 9);
+        assert_eq!(level.progress, 0.5);
+        assert_eq!(level.now_play_count, 12345);
+        assert_eq!(level.next_play_count, 20000);
+        assert_eq!(level.next_login_count, 800);
+    }
+
+    #[test]
+    fn parse_user_record_response() {
+        let response: UserRecordResponse = parse_response(include_bytes!("../../tests/fixtures/user_record.json")).unwrap();
+        assert!(response.all_data.is_empty());
+
+        let ranked_songs: Vec<RankedSong> = response.week_data.into_iter().map(RankedSong::from).collect();
+        assert_eq!(ranked_songs.len(), 2);
+        assert_eq!(ranked_songs[0].song.name, "晴天");
+        assert_eq!(ranked_songs[0].play_count, 23);
+        assert_eq!(ranked_songs[0].score, 100);
+        assert_eq!(ranked_songs[1].song.id, 254574);
+        assert_eq!(ranked_songs[1].score, 52);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::Quality;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -5,6 +6,10 @@ use serde::{Deserialize, Serialize};
 pub struct Settings {
     pub use_remote_api: bool,
     pub remote_api_url: String,
+    /// 首选音质
+    pub quality: Quality,
+    /// 首选音质获取不到时，依次尝试的音质
+    pub quality_fallbacks: Vec<Quality>,
 }
 
 impl Default for Settings {
@@ -12,6 +17,8 @@ impl Default for Settings {
         Self {
             use_remote_api: false,
             remote_api_url: String::from("https://ncm-api-wine.vercel.app/"),
+            quality: Quality::Jymaster,
+            quality_fallbacks: vec![Quality::Lossless, Quality::Exhigh, Quality::Standard],
         }
     }
 }
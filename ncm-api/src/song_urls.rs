@@ -0,0 +1,87 @@
+use crate::model::{Quality, SongUrl};
+use crate::responses::parse_response;
+use crate::responses::song::SongUrlResponse;
+use crate::NcmResult;
+use log::debug;
+use reqwest::Client;
+use std::collections::{HashMap, VecDeque};
+
+/// 每次请求的歌曲数量上限（id 以逗号拼接在 url 中）
+const SONG_URLS_BATCH_LIMIT: usize = 100;
+
+/// 歌曲链接的批量加载器
+///
+/// 由 `NcmClient::song_urls_loader()` 创建，已确定各歌曲依次尝试的音质。加载期间无需持有 `NcmClient` （及其锁），
+/// 可在后台任务中预先获取即将播放的歌曲链接
+#[derive(Debug, Clone)]
+pub struct SongUrlsLoader {
+    http_client: Client,
+    api_url: String,
+    cookie: String,
+    /// (歌曲 id, 依次尝试的音质)
+    candidates: Vec<(u64, Vec<Quality>)>,
+}
+
+impl SongUrlsLoader {
+    pub(crate) fn new(http_client: Client, api_url: String, cookie: String, candidates: Vec<(u64, Vec<Quality>)>) -> Self {
+        Self {
+            http_client,
+            api_url,
+            cookie,
+            candidates,
+        }
+    }
+
+    /// 加载所有歌曲的链接，结果包含每一首歌曲（不可获取的歌曲链接为 None）
+    ///
+    /// 同一音质的歌曲合并为一次请求，获取不到链接的歌曲再以下一个音质重试
+    pub async fn load(self) -> NcmResult<HashMap<u64, SongUrl>> {
+        let mut pending: Vec<(u64, VecDeque<Quality>)> = self.candidates.iter().map(|(song_id, qualities)| (*song_id, qualities.iter().copied().collect())).collect();
+        let mut song_urls: HashMap<u64, SongUrl> = HashMap::new();
+
+        while !pending.is_empty() {
+            // 按本轮尝试的音质分组
+            let mut groups: HashMap<Quality, Vec<u64>> = HashMap::new();
+            for (song_id, qualities) in pending.iter_mut() {
+                if let Some(quality) = qualities.pop_front() {
+                    groups.entry(quality).or_default().push(*song_id);
+                }
+            }
+            if groups.is_empty() {
+                break;
+            }
+
+            for (quality, song_ids) in groups {
+                for chunk in song_ids.chunks(SONG_URLS_BATCH_LIMIT) {
+                    for song_url in self.request(chunk, quality).await? {
+                        // 已获取到链接的不再覆盖，不可获取的先记录，后续音质获取到时再覆盖
+                        if !song_urls.get(&song_url.song_id).is_some_and(SongUrl::is_available) {
+                            song_urls.insert(song_url.song_id, song_url);
+                        }
+                    }
+                }
+            }
+
+            pending.retain(|(song_id, qualities)| !qualities.is_empty() && !song_urls.get(song_id).is_some_and(SongUrl::is_available));
+        }
+
+        debug!("loaded urls of {} songs", song_urls.len());
+
+        Ok(song_urls)
+    }
+
+    async fn request(&self, song_ids: &[u64], quality: Quality) -> NcmResult<Vec<SongUrl>> {
+        let ids: Vec<String> = song_ids.iter().map(u64::to_string).collect();
+
+        let song_url_response = self
+            .http_client
+            .post(format!("{}/song/url/v1?id={}&level={}", &self.api_url, ids.join(","), quality.level()))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let song_url_response: SongUrlResponse = parse_response(&song_url_response.bytes().await?)?;
+
+        Ok(song_url_response.data.into_iter().map(SongUrl::from).collect())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::model::SongAvailability;
+use crate::responses::parse_response;
+use crate::responses::song::SongDetailResponse;
+use crate::NcmResult;
+use log::debug;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// 每次请求的歌曲数量上限（id 以逗号拼接在 url 中）
+const SONGS_AVAILABILITY_BATCH_LIMIT: usize = 200;
+
+/// 歌曲可获取状态的批量检查器
+///
+/// 由 `NcmClient::songs_availability_loader()` 创建，根据歌曲详情中的播放权限判断可获取状态。
+/// 检查期间无需持有 `NcmClient` （及其锁），可在后台任务中检查整个播放列表
+#[derive(Debug, Clone)]
+pub struct SongsAvailabilityLoader {
+    http_client: Client,
+    api_url: String,
+    cookie: String,
+    song_ids: Vec<u64>,
+}
+
+impl SongsAvailabilityLoader {
+    pub(crate) fn new(http_client: Client, api_url: String, cookie: String, song_ids: Vec<u64>) -> Self {
+        Self {
+            http_client,
+            api_url,
+            cookie,
+            song_ids,
+        }
+    }
+
+    /// 检查所有歌曲的可获取状态，结果不含未返回播放权限的歌曲
+    pub async fn load(self) -> NcmResult<HashMap<u64, SongAvailability>> {
+        let mut songs_availability: HashMap<u64, SongAvailability> = HashMap::new();
+
+        for chunk in self.song_ids.chunks(SONGS_AVAILABILITY_BATCH_LIMIT) {
+            songs_availability.extend(self.request(chunk).await?);
+        }
+
+        debug!("checked availability of {} songs", songs_availability.len());
+
+        Ok(songs_availability)
+    }
+
+    async fn request(&self, song_ids: &[u64]) -> NcmResult<Vec<(u64, SongAvailability)>> {
+        let ids: Vec<String> = song_ids.iter().map(u64::to_string).collect();
+
+        let song_detail_response = self
+            .http_client
+            .post(format!("{}/song/detail?ids={}", &self.api_url, ids.join(",")))
+            .form(&[("cookie", &self.cookie)])
+            .send()
+            .await?;
+
+        let song_detail_response: SongDetailResponse = parse_response(&song_detail_response.bytes().await?)?;
+
+        Ok(song_detail_response
+            .privileges
+            .into_iter()
+            .map(|privilege| (privilege.id, privilege.availability()))
+            .collect())
+    }
+}
@@ -15,12 +15,14 @@ pub enum PlayState {
     Ended,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PlayMode {
     Single,
     SingleRepeat,
     ListRepeat,
     Shuffle,
+    /// 私人 FM ，后续歌曲从 FM 中获取
+    PersonalFm,
 }
 
 impl fmt::Display for PlayMode {
@@ -30,6 +32,7 @@ impl fmt::Display for PlayMode {
             PlayMode::SingleRepeat => write!(f, "单曲循环"),
             PlayMode::ListRepeat => write!(f, "列表循环"),
             PlayMode::Shuffle => write!(f, "随机播放"),
+            PlayMode::PersonalFm => write!(f, "私人FM"),
         }
     }
 }
@@ -4,39 +4,85 @@ pub use config::*;
 use anyhow::{anyhow, Result};
 use gstreamer::ClockTime;
 use gstreamer_play::{gst, Play, PlayVideoRenderer};
-use log::{debug, trace};
-use ncm_api::model::Songlist;
+use log::{debug, error, trace};
+use ncm_api::model::{DjRadio, Program, SongAvailability, SongUrl, Songlist};
 use ncm_api::{
     model::{Lyrics, Song},
-    NcmClient,
+    NcmClient, NcmError, NcmResult,
 };
 use rand::{thread_rng, Rng};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::sync::MutexGuard;
+use tokio::task::JoinHandle;
+
+/// 预先获取链接的即将播放的歌曲数量
+const LOOK_AHEAD_SONGS: usize = 3;
+/// 每次后台检查可获取状态的歌曲数量，检查完一批即刷新界面
+const AVAILABILITY_CHECK_BATCH: usize = 500;
+/// 后台获取失败后，间隔一段时间再重试
+const BACKGROUND_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+/// 私人 FM 待播歌曲少于该数量时在后台补充
+const PERSONAL_FM_BUFFER_LOW: usize = 2;
+/// 私人 FM 播放列表保留的歌曲数量，超出时移除最早播放的歌曲
+const PERSONAL_FM_PLAYLIST_LIMIT: usize = 100;
+/// 播客节目的播放位置每隔一段时间写入磁盘
+const PROGRAM_POSITIONS_STORE_INTERVAL: Duration = Duration::from_secs(10);
 
 pub struct Player {
     play: Play,
     //
     play_state: PlayState,
     play_mode: PlayMode,
+    play_mode_before_personal_fm: PlayMode, // 离开私人 FM 时恢复的播放模式
+    is_heart_mode: bool,                    // 心动模式下按推荐顺序播放，切换歌单后退出
+    is_podcast: bool,                       // 播放列表为电台节目时记录各节目的播放位置
     //
     volume: f64,
     //
     songlists: Vec<Songlist>,
+    songlists_revision: usize, // 用户歌单每次更新后递增，界面据此刷新
     //
+    current_playlist_id: Option<u64>,
     current_playlist_name: String,
-    current_playlist: Vec<Song>, // TODO: 优化为指针
+    current_playlist: Vec<Song>,      // TODO: 优化为指针
+    current_playlist_revision: usize, // 播放列表每次被替换、追加歌曲或调整顺序后递增，界面据此刷新
     //
     play_index_history_stack: Vec<usize>, // 历史记录，保存播放的歌曲在 playlist 中的 index，栈顶为当前播放
+    play_queue: VecDeque<usize>,          // 待播队列，保存加入队列的歌曲在 playlist 中的 index，优先于播放模式
+    shuffle_order: VecDeque<usize>,       // 随机播放模式下预先抽取的后续歌曲 index ，以便预先获取链接
     //
     current_song_index: Option<usize>,
     current_song: Option<Song>,
+    current_song_url: Option<SongUrl>,
+    //
+    song_urls: HashMap<u64, SongUrl>, // 预先获取的即将播放歌曲的链接
+    song_urls_prefetch: Option<JoinHandle<NcmResult<HashMap<u64, SongUrl>>>>,
+    song_urls_prefetch_retry_at: Option<Instant>,
+    //
+    songs_availability: HashMap<u64, SongAvailability>, // 已检查的歌曲可获取状态
+    songs_availability_revision: usize,                 // 可获取状态每次更新后递增，界面据此刷新
+    availability_check: Option<JoinHandle<NcmResult<HashMap<u64, SongAvailability>>>>,
+    availability_check_retry_at: Option<Instant>,
+    skipped_songs: Vec<Song>, // 因不可获取而跳过的歌曲，由界面取出后在命令行提示
+    //
+    personal_fm_buffer: VecDeque<Song>, // 私人 FM 的待播歌曲，播放时追加到播放列表
+    personal_fm_refill: Option<JoinHandle<NcmResult<Vec<Song>>>>,
+    personal_fm_refill_retry_at: Option<Instant>,
+    //
+    program_positions: HashMap<u64, u64>, // 各节目（以节目音频的歌曲 id 为键）上次的播放位置（ms）
+    program_positions_path: PathBuf,
+    program_positions_changed: bool,
+    program_positions_stored_at: Instant,
     //
     current_song_lyrics: Option<Lyrics>,
     current_lyric_line_index: Option<usize>,
 }
 
 impl Player {
-    pub fn new() -> Self {
+    pub fn new(program_positions_path: PathBuf) -> Self {
         gst::init().expect("Failed to initialize GST");
 
         let play = Play::new(None::<PlayVideoRenderer>);
@@ -54,13 +100,37 @@ impl Player {
             play,
             play_state: PlayState::Stopped,
             play_mode: PlayMode::Shuffle,
+            play_mode_before_personal_fm: PlayMode::Shuffle,
+            is_heart_mode: false,
+            is_podcast: false,
             volume,
             songlists: Vec::new(),
+            songlists_revision: 0,
+            current_playlist_id: None,
             current_playlist_name: String::new(),
             current_playlist: Vec::new(),
+            current_playlist_revision: 0,
             play_index_history_stack: Vec::new(),
+            play_queue: VecDeque::new(),
+            shuffle_order: VecDeque::new(),
             current_song_index: None,
             current_song: None,
+            current_song_url: None,
+            song_urls: HashMap::new(),
+            song_urls_prefetch: None,
+            song_urls_prefetch_retry_at: None,
+            songs_availability: HashMap::new(),
+            songs_availability_revision: 0,
+            availability_check: None,
+            availability_check_retry_at: None,
+            skipped_songs: Vec::new(),
+            personal_fm_buffer: VecDeque::new(),
+            personal_fm_refill: None,
+            personal_fm_refill_retry_at: None,
+            program_positions: read_program_positions(&program_positions_path),
+            program_positions_path,
+            program_positions_changed: false,
+            program_positions_stored_at: Instant::now(),
             current_song_lyrics: None,
             current_lyric_line_index: None,
         }
@@ -96,7 +166,29 @@ impl Player {
         self.play_mode.to_string()
     }
 
+    pub fn is_heart_mode(&self) -> bool {
+        self.is_heart_mode
+    }
+
+    /// 设置播放模式
+    ///
+    /// 进入私人 FM 时切换到单独的播放列表，之后播放的 FM 歌曲依次追加到该列表
     pub fn set_play_mode(&mut self, mode: PlayMode) {
+        if mode == PlayMode::PersonalFm && self.play_mode != PlayMode::PersonalFm {
+            self.play_mode_before_personal_fm = self.play_mode.clone();
+
+            self.current_playlist_id = None;
+            self.current_playlist_name = String::from("私人FM");
+            self.current_playlist = Vec::new();
+            self.current_playlist_revision += 1;
+            self.play_index_history_stack = Vec::new();
+            self.play_queue.clear();
+            self.shuffle_order.clear();
+            self.current_song_index = None;
+            self.is_heart_mode = false;
+            self.is_podcast = false;
+        }
+
         self.play_mode = mode;
     }
 
@@ -108,6 +200,11 @@ impl Player {
         self.play.position()
     }
 
+    /// 当前播放列表对应的歌单 id （由待播队列生成的播放列表及专辑等临时歌单为 None）
+    pub fn current_playlist_id(&self) -> Option<u64> {
+        self.current_playlist_id
+    }
+
     pub fn current_playlist_name(&self) -> &String {
         &self.current_playlist_name
     }
@@ -116,6 +213,10 @@ impl Player {
         &self.current_playlist
     }
 
+    pub fn current_playlist_revision(&self) -> usize {
+        self.current_playlist_revision
+    }
+
     pub fn current_song(&self) -> &Option<Song> {
         &self.current_song
     }
@@ -132,37 +233,217 @@ impl Player {
         self.current_lyric_line_index
     }
 
-    pub fn set_songlists(&mut self, songlists: Vec<Songlist>) {
+    /// 设置用户歌单，歌曲数量未变化的歌单保留已装载的歌曲
+    pub fn set_songlists(&mut self, mut songlists: Vec<Songlist>) {
+        for songlist in songlists.iter_mut() {
+            if let Some(old_songlist) = self
+                .songlists
+                .iter()
+                .find(|old_songlist| old_songlist.id == songlist.id && old_songlist.songs_count == songlist.songs_count)
+            {
+                songlist.songs = old_songlist.songs.clone();
+            }
+        }
+
         self.songlists = songlists;
+        self.songlists_revision += 1;
+    }
+
+    /// 追加歌单（分页获取用户歌单时使用）
+    pub fn append_songlists(&mut self, songlists: Vec<Songlist>) {
+        self.songlists.extend(songlists);
+        self.songlists_revision += 1;
+    }
+
+    pub fn songlists_revision(&self) -> usize {
+        self.songlists_revision
     }
 
     pub fn songlists(&self) -> &Vec<Songlist> {
         &self.songlists
     }
+
+    /// 歌曲的可获取状态（尚未检查时为 Unknown）
+    pub fn song_availability(&self, song_id: u64) -> SongAvailability {
+        self.songs_availability.get(&song_id).copied().unwrap_or_default()
+    }
+
+    pub fn songs_availability_revision(&self) -> usize {
+        self.songs_availability_revision
+    }
+
+    /// 取出自上次调用以来因不可获取而跳过的歌曲
+    pub fn take_skipped_songs(&mut self) -> Vec<Song> {
+        std::mem::take(&mut self.skipped_songs)
+    }
 }
 
 /// playlist
 impl Player {
-    /// 切换播放列表
-    pub async fn switch_playlist<'c>(&mut self, playlist_candidate_index: usize, ncm_client_guard: MutexGuard<'c, NcmClient>) -> Result<()> {
-        if let Some(songlist) = self.songlists.get_mut(playlist_candidate_index) {
-            debug!("{:?}", songlist);
+    /// 切换到用户歌单，歌单需已装载歌曲（由调用方在不持有锁时加载）
+    ///
+    /// 装载的歌曲同时保存到用户歌单中
+    pub fn switch_playlist(&mut self, songlist: &Songlist) {
+        for user_songlist in self.songlists.iter_mut().filter(|user_songlist| user_songlist.id == songlist.id) {
+            user_songlist.songs = songlist.songs.clone();
+            apply_songs_availability(&mut user_songlist.songs, &self.songs_availability);
+        }
 
-            //
-            ncm_client_guard.load_songlist_songs(songlist).await?;
+        self.switch_to_songlist(songlist);
+    }
 
-            //
-            self.current_playlist_name = songlist.name.clone();
-            self.current_playlist = songlist.songs.clone();
-            self.play_index_history_stack = Vec::new();
-            self.current_song_index = if self.current_playlist.is_empty() { None } else { Some(0) };
+    /// 切换到已装载歌曲的歌单（如搜索结果），该歌单不必在用户歌单中
+    pub fn switch_to_songlist(&mut self, songlist: &Songlist) {
+        debug!("{:?}", songlist);
+
+        self.leave_personal_fm();
+        self.is_heart_mode = false;
+        self.is_podcast = false;
+
+        // 专辑等临时歌单的 id 不是歌单 id ，不能与用户歌单对应
+        self.current_playlist_id = songlist.songlist_id();
+        self.current_playlist_name = songlist.name.clone();
+        self.current_playlist = songlist.songs.clone();
+        self.current_playlist_revision += 1;
+        apply_songs_availability(&mut self.current_playlist, &self.songs_availability);
+        self.play_index_history_stack = Vec::new();
+        self.play_queue.clear();
+        self.shuffle_order.clear();
+        self.current_song_index = if self.current_playlist.is_empty() { None } else { Some(0) };
+
+        // 中止正在进行的检查，避免旧播放列表的结果应用到新播放列表，由 auto_play 优先检查新播放列表中歌曲的可获取状态
+        self.abort_availability_check();
+    }
 
-            Ok(())
-        } else {
-            Err(anyhow!("no playlist candidates found"))
+    /// 进入心动模式：以当前歌曲开头、之后为心动模式推荐的歌曲替换播放列表，当前歌曲继续播放
+    pub fn start_heart_mode(&mut self, songs: Vec<Song>) -> Result<()> {
+        let current_song = match self.current_song.clone() {
+            Some(current_song) => current_song,
+            None => return Err(anyhow!("当前没有正在播放的歌曲")),
+        };
+
+        self.leave_personal_fm();
+        self.is_heart_mode = true;
+        self.is_podcast = false;
+
+        self.current_playlist_id = None;
+        self.current_playlist_name = String::from("心动模式");
+        self.current_playlist = vec![current_song.clone()];
+        self.current_playlist.extend(songs.into_iter().filter(|song| song.id != current_song.id));
+        self.current_playlist_revision += 1;
+        apply_songs_availability(&mut self.current_playlist, &self.songs_availability);
+        self.play_index_history_stack = vec![0];
+        self.play_queue.clear();
+        self.shuffle_order.clear();
+        self.current_song_index = Some(0);
+
+        // 后续歌曲链接按新的播放列表重新预取
+        self.clear_song_urls();
+
+        Ok(())
+    }
+
+    /// 将歌曲加入待播队列，当前歌曲结束后按加入顺序优先播放
+    ///
+    /// 不在当前播放列表中的歌曲会被追加到播放列表末尾
+    pub fn enqueue_songs(&mut self, songs: &[Song]) {
+        if self.current_playlist_name.is_empty() {
+            self.current_playlist_name = String::from("播放队列");
+        }
+
+        for song in songs {
+            let index = match self.current_playlist.iter().position(|s| s.id == song.id) {
+                Some(index) => index,
+                None => {
+                    let mut song = song.clone();
+                    song.availability = self.song_availability(song.id);
+                    self.current_playlist.push(song);
+                    self.current_playlist_revision += 1;
+                    self.current_playlist.len() - 1
+                },
+            };
+            self.play_queue.push_back(index);
+        }
+    }
+
+    /// 将用户歌单中 from 处的歌曲移动到 to 处，正在播放该歌单时同步调整播放列表
+    pub fn move_songlist_song(&mut self, songlist_id: u64, from: usize, to: usize) {
+        for songlist in self.songlists.iter_mut().filter(|songlist| songlist.id == songlist_id) {
+            if from < songlist.songs.len() && to < songlist.songs.len() {
+                let song = songlist.songs.remove(from);
+                songlist.songs.insert(to, song);
+            }
+        }
+
+        if self.current_playlist_id == Some(songlist_id) && from < self.current_playlist.len() && to < self.current_playlist.len() {
+            let song = self.current_playlist.remove(from);
+            self.current_playlist.insert(to, song);
+            self.current_playlist_revision += 1;
+
+            // 播放列表中保存的 index 随之调整
+            let moved_index = |index: usize| {
+                if index == from {
+                    to
+                } else if from < index && index <= to {
+                    index - 1
+                } else if to <= index && index < from {
+                    index + 1
+                } else {
+                    index
+                }
+            };
+            self.current_song_index = self.current_song_index.map(moved_index);
+            self.play_index_history_stack.iter_mut().for_each(|index| *index = moved_index(*index));
+            self.play_queue.iter_mut().for_each(|index| *index = moved_index(*index));
+            self.shuffle_order.iter_mut().for_each(|index| *index = moved_index(*index));
         }
     }
 
+    /// 停止播放并清空所有歌单和播放列表（登出时调用）
+    pub fn reset(&mut self) {
+        // 停止前保存播客节目的播放位置
+        if self.is_podcast {
+            self.record_program_position();
+        }
+        self.store_program_positions();
+
+        self.play.stop();
+        self.play_state = PlayState::Stopped;
+
+        self.songlists = Vec::new();
+        self.songlists_revision += 1;
+        self.current_playlist_id = None;
+        self.current_playlist_name = String::new();
+        self.current_playlist = Vec::new();
+        self.current_playlist_revision += 1;
+        self.play_index_history_stack = Vec::new();
+        self.play_queue.clear();
+        self.shuffle_order.clear();
+        self.current_song_index = None;
+        self.current_song = None;
+        self.current_song_url = None;
+        self.current_song_lyrics = None;
+        self.current_lyric_line_index = None;
+
+        self.clear_song_urls();
+
+        // 可获取状态与账号（会员）有关
+        self.abort_availability_check();
+        self.songs_availability.clear();
+        self.songs_availability_revision += 1;
+        self.skipped_songs.clear();
+
+        // 私人 FM 、心动模式与账号有关
+        self.leave_personal_fm();
+        self.is_heart_mode = false;
+        self.is_podcast = false;
+        if let Some(personal_fm_refill) = self.personal_fm_refill.take() {
+            personal_fm_refill.abort();
+        }
+        self.personal_fm_refill_retry_at = None;
+        self.personal_fm_buffer.clear();
+    }
+
     /// 向后搜索歌单（向上方搜索）
     pub fn search_backward_playlist(&mut self, start_index: usize, keywords: Vec<String>) -> Option<usize> {
         if start_index < self.current_playlist.len() {
@@ -226,6 +507,24 @@ impl Player {
 
     /// 自动播放
     pub async fn auto_play<'c>(&mut self, ncm_client_guard: MutexGuard<'c, NcmClient>) -> Result<()> {
+        // 在后台检查播放列表中歌曲的可获取状态
+        self.update_availability_check(&ncm_client_guard).await;
+
+        // 在后台补充私人 FM 的待播歌曲
+        if self.play_mode == PlayMode::PersonalFm {
+            self.update_personal_fm_refill(&ncm_client_guard).await;
+        }
+
+        if self.play_state != PlayState::Stopped {
+            // 预先获取即将播放的歌曲链接
+            self.update_song_urls_prefetch(&ncm_client_guard).await;
+
+            // 暂停期间当前歌曲的链接过期时，重新获取
+            if self.play_state == PlayState::Paused && self.current_song_url.as_ref().is_some_and(SongUrl::is_expired) {
+                self.refresh_current_song_url(&ncm_client_guard).await;
+            }
+        }
+
         // 判断一首歌是否播放完
         if self.play_state == PlayState::Playing {
             if let (Some(position), Some(duration)) = (self.position(), self.duration()) {
@@ -238,11 +537,17 @@ impl Player {
             }
         }
 
+        // 记录播客节目的播放位置
+        if self.is_podcast {
+            self.record_program_position();
+        }
+
         if self.play_state == PlayState::Playing {
             // 当前歌曲仍在播放，推进歌词
             self.auto_lyric_forward();
         } else if self.play_state == PlayState::Ended {
             // 播放下一首
+            self.ensure_personal_fm_buffer(&ncm_client_guard).await?;
             self.update_next_to_play();
             self.play_next(ncm_client_guard).await?;
         }
@@ -265,8 +570,15 @@ impl Player {
 
     /// 根据当前模式开始播放
     pub async fn start_play<'c>(&mut self, ncm_client_guard: MutexGuard<'c, NcmClient>) -> Result<()> {
+        // 私人 FM 不需要播放列表
+        if self.play_mode == PlayMode::PersonalFm {
+            self.ensure_personal_fm_buffer(&ncm_client_guard).await?;
+            self.update_next_to_play();
+            return self.play_next(ncm_client_guard).await;
+        }
+
         if !self.current_playlist.is_empty() {
-            match self.play_mode {
+            match self.effective_play_mode() {
                 PlayMode::ListRepeat => {
                     self.current_song_index = Some(0);
                     self.current_song = Some(self.current_playlist[0].clone());
@@ -280,7 +592,7 @@ impl Player {
                     self.play_next(ncm_client_guard).await?;
                     Ok(())
                 },
-                _ => Err(anyhow!("start命令只在`列表循环`和`随机播放`模式下有效")),
+                _ => Err(anyhow!("start命令只在`列表循环`、`随机播放`和`私人FM`模式下有效")),
             }
         } else {
             Err(anyhow!("请先选择歌单"))
@@ -293,6 +605,7 @@ impl Player {
             // 当前单曲播放半秒后才可以切换到下一首，留出缓冲时间，防止切换过快
             if let Some(position) = self.position() {
                 if position.mseconds() >= 500 {
+                    self.ensure_personal_fm_buffer(&ncm_client_guard).await?;
                     self.update_next_to_play();
 
                     debug!("[{:?}] {:?}, ", self.current_song_index, self.current_song);
@@ -344,12 +657,433 @@ impl Player {
     }
 }
 
+/// 歌曲链接预取
+impl Player {
+    /// 清空预先获取的歌曲链接（首选音质改变后调用）
+    pub fn clear_song_urls(&mut self) {
+        if let Some(prefetch) = self.song_urls_prefetch.take() {
+            prefetch.abort();
+        }
+        self.song_urls.clear();
+        self.song_urls_prefetch_retry_at = None;
+    }
+
+    /// 按播放顺序接下来要播放的歌曲在 playlist 中的 index ，最多 LOOK_AHEAD_SONGS 首
+    fn upcoming_song_indices(&mut self) -> Vec<usize> {
+        let len = self.current_playlist.len();
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let mut indices: Vec<usize> = self.play_queue.iter().copied().filter(|index| *index < len).take(LOOK_AHEAD_SONGS).collect();
+        let rest = LOOK_AHEAD_SONGS - indices.len();
+
+        if let Some(current_song_index) = self.current_song_index {
+            match self.effective_play_mode() {
+                PlayMode::Single => {},
+                PlayMode::SingleRepeat => indices.push(current_song_index),
+                PlayMode::ListRepeat => indices.extend((1..=rest).map(|offset| (current_song_index + offset) % len)),
+                // 私人 FM 的后续歌曲不在播放列表中
+                PlayMode::PersonalFm => {},
+                PlayMode::Shuffle => {
+                    // 预先抽取随机播放的后续歌曲，update_next_to_play 按同样的顺序取出
+                    while self.shuffle_order.len() < rest {
+                        self.shuffle_order.push_back(thread_rng().gen_range(0..len));
+                    }
+                    indices.extend(self.shuffle_order.iter().take(rest));
+                },
+            }
+        }
+
+        let mut upcoming_song_indices = Vec::with_capacity(indices.len());
+        for index in indices {
+            if !upcoming_song_indices.contains(&index) {
+                upcoming_song_indices.push(index);
+            }
+        }
+
+        upcoming_song_indices
+    }
+
+    /// 在后台预先获取接下来要播放的歌曲链接，上一次获取完成后合并结果
+    async fn update_song_urls_prefetch(&mut self, ncm_client: &NcmClient) {
+        if let Some(prefetch) = self.song_urls_prefetch.take() {
+            if !prefetch.is_finished() {
+                self.song_urls_prefetch = Some(prefetch);
+                return;
+            }
+
+            match prefetch.await {
+                Ok(Ok(song_urls)) => self.song_urls.extend(song_urls),
+                Ok(Err(e)) => {
+                    debug!("failed to prefetch song urls: {:?}", e);
+                    self.song_urls_prefetch_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+                Err(e) => {
+                    debug!("song urls prefetch task failed: {:?}", e);
+                    self.song_urls_prefetch_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+            }
+        }
+
+        if self.song_urls_prefetch_retry_at.is_some_and(|retry_at| Instant::now() < retry_at) {
+            return;
+        }
+        self.song_urls_prefetch_retry_at = None;
+
+        let mut upcoming_songs: Vec<Song> = self.upcoming_song_indices().into_iter().filter_map(|index| self.current_playlist.get(index)).cloned().collect();
+        if self.play_mode == PlayMode::PersonalFm {
+            let rest = LOOK_AHEAD_SONGS.saturating_sub(upcoming_songs.len());
+            upcoming_songs.extend(self.personal_fm_buffer.iter().take(rest).cloned());
+        }
+
+        // 只保留接下来要播放的歌曲链接，过期的链接重新获取
+        self.song_urls
+            .retain(|song_id, song_url| !song_url.is_expired() && upcoming_songs.iter().any(|song| song.id == *song_id));
+        let songs_to_prefetch: Vec<Song> = upcoming_songs.into_iter().filter(|song| !self.song_urls.contains_key(&song.id)).collect();
+
+        if !songs_to_prefetch.is_empty() {
+            trace!("prefetch song urls: {:?}", songs_to_prefetch.iter().map(|song| song.id).collect::<Vec<u64>>());
+            self.song_urls_prefetch = Some(tokio::spawn(ncm_client.song_urls_loader(&songs_to_prefetch).load()));
+        }
+    }
+
+    /// 重新获取当前歌曲的链接，并回到原来的播放位置（暂停期间链接过期时调用）
+    async fn refresh_current_song_url(&mut self, ncm_client: &NcmClient) {
+        let current_song = match self.current_song.as_ref() {
+            Some(current_song) => current_song.clone(),
+            None => return,
+        };
+
+        let song_url = match ncm_client.load_song_urls(std::slice::from_ref(&current_song)).await {
+            Ok(mut song_urls) => song_urls.remove(&current_song.id).filter(SongUrl::is_available),
+            Err(e) => {
+                debug!("failed to refresh song url of {}: {:?}", current_song.id, e);
+                None
+            },
+        };
+
+        match song_url {
+            Some(song_url) => {
+                if let Some(url) = song_url.url.as_deref() {
+                    let position = self.position();
+
+                    self.play.set_uri(Some(url));
+                    self.play.pause();
+                    if let Some(position) = position {
+                        self.play.seek(position);
+                    }
+                    self.play.set_volume(self.volume);
+
+                    debug!("refreshed song url of {}", current_song.id);
+                }
+                self.current_song_url = Some(song_url);
+            },
+            // 刷新失败时不再重试，恢复播放后由 gstreamer 继续使用原链接
+            None => self.current_song_url = None,
+        }
+    }
+}
+
+/// 歌曲可获取状态
+impl Player {
+    /// 中止正在进行的检查（切换播放列表或登出时调用），下一次 auto_play 时重新检查当前播放列表
+    fn abort_availability_check(&mut self) {
+        if let Some(availability_check) = self.availability_check.take() {
+            availability_check.abort();
+        }
+        self.availability_check_retry_at = None;
+    }
+
+    /// 检查当前播放列表中尚未检查的歌曲，每次最多 AVAILABILITY_CHECK_BATCH 首
+    fn start_availability_check(&mut self, ncm_client: &NcmClient) {
+        let mut song_ids: Vec<u64> = Vec::new();
+        for song in self.current_playlist.iter() {
+            if !self.songs_availability.contains_key(&song.id) && !song_ids.contains(&song.id) {
+                song_ids.push(song.id);
+                if song_ids.len() >= AVAILABILITY_CHECK_BATCH {
+                    break;
+                }
+            }
+        }
+
+        if !song_ids.is_empty() {
+            trace!("check availability of {} songs", song_ids.len());
+
+            let loader = ncm_client.songs_availability_loader(&song_ids);
+            self.availability_check = Some(tokio::spawn(async move {
+                let songs_availability = loader.load().await?;
+
+                // 未返回结果的歌曲记为 Unknown ，不再重复检查
+                Ok(song_ids
+                    .into_iter()
+                    .map(|song_id| (song_id, songs_availability.get(&song_id).copied().unwrap_or_default()))
+                    .collect())
+            }));
+        }
+    }
+
+    /// 合并已完成的检查结果，并继续检查剩余的歌曲
+    async fn update_availability_check(&mut self, ncm_client: &NcmClient) {
+        if let Some(availability_check) = self.availability_check.take() {
+            if !availability_check.is_finished() {
+                self.availability_check = Some(availability_check);
+                return;
+            }
+
+            match availability_check.await {
+                Ok(Ok(songs_availability)) => self.record_songs_availability(songs_availability),
+                Ok(Err(e)) => {
+                    debug!("failed to check songs availability: {:?}", e);
+                    self.availability_check_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+                Err(e) => {
+                    debug!("songs availability check task failed: {:?}", e);
+                    self.availability_check_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+            }
+        }
+
+        if self.availability_check_retry_at.is_some_and(|retry_at| Instant::now() < retry_at) {
+            return;
+        }
+        self.availability_check_retry_at = None;
+
+        self.start_availability_check(ncm_client);
+    }
+
+    /// 记录歌曲的可获取状态，并同步到播放列表和歌单中的歌曲
+    fn record_songs_availability(&mut self, songs_availability: HashMap<u64, SongAvailability>) {
+        self.songs_availability.extend(songs_availability);
+        self.songs_availability_revision += 1;
+
+        apply_songs_availability(&mut self.current_playlist, &self.songs_availability);
+        for songlist in self.songlists.iter_mut() {
+            apply_songs_availability(&mut songlist.songs, &self.songs_availability);
+        }
+    }
+}
+
+/// 私人 FM
+impl Player {
+    /// 私人 FM 模式下将当前歌曲移入垃圾桶并立刻播放下一首，返回被移入垃圾桶的歌曲
+    pub async fn trash_current_song<'c>(&mut self, ncm_client_guard: MutexGuard<'c, NcmClient>) -> Result<Song> {
+        if self.play_mode != PlayMode::PersonalFm {
+            return Err(anyhow!("只有`私人FM`模式下可以将歌曲移入垃圾桶"));
+        }
+        let song = match self.current_song.clone() {
+            Some(song) => song,
+            None => return Err(anyhow!("当前没有正在播放的歌曲")),
+        };
+
+        ncm_client_guard.trash_personal_fm_song(song.id).await?;
+
+        self.ensure_personal_fm_buffer(&ncm_client_guard).await?;
+        self.update_next_to_play();
+        self.play_next(ncm_client_guard).await?;
+
+        Ok(song)
+    }
+
+    /// 离开私人 FM （切换到歌单时调用），恢复进入前的播放模式
+    fn leave_personal_fm(&mut self) {
+        if self.play_mode == PlayMode::PersonalFm {
+            self.play_mode = self.play_mode_before_personal_fm.clone();
+        }
+    }
+
+    /// 私人 FM 播放列表超过 PERSONAL_FM_PLAYLIST_LIMIT 首时移除最早播放的歌曲，播放列表中保存的 index 随之调整
+    ///
+    /// 仍在待播队列中的歌曲不会被移除
+    fn trim_personal_fm_playlist(&mut self) {
+        let excess = self
+            .current_playlist
+            .len()
+            .saturating_sub(PERSONAL_FM_PLAYLIST_LIMIT)
+            .min(self.play_queue.iter().copied().min().unwrap_or(usize::MAX));
+        if excess == 0 {
+            return;
+        }
+
+        self.current_playlist.drain(..excess);
+        self.current_playlist_revision += 1;
+
+        self.current_song_index = self.current_song_index.and_then(|index| index.checked_sub(excess));
+        self.play_index_history_stack.retain(|index| *index >= excess);
+        self.play_index_history_stack.iter_mut().for_each(|index| *index -= excess);
+        self.play_queue.iter_mut().for_each(|index| *index -= excess);
+        self.shuffle_order.clear();
+    }
+
+    /// 合并后台补充的歌曲，待播歌曲不足时继续补充
+    async fn update_personal_fm_refill(&mut self, ncm_client: &NcmClient) {
+        if let Some(personal_fm_refill) = self.personal_fm_refill.take() {
+            if !personal_fm_refill.is_finished() {
+                self.personal_fm_refill = Some(personal_fm_refill);
+                return;
+            }
+
+            match personal_fm_refill.await {
+                Ok(Ok(songs)) => self.personal_fm_buffer.extend(songs),
+                Ok(Err(e)) => {
+                    debug!("failed to refill personal fm: {:?}", e);
+                    self.personal_fm_refill_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+                Err(e) => {
+                    debug!("personal fm refill task failed: {:?}", e);
+                    self.personal_fm_refill_retry_at = Some(Instant::now() + BACKGROUND_RETRY_INTERVAL);
+                },
+            }
+        }
+
+        if self.personal_fm_refill_retry_at.is_some_and(|retry_at| Instant::now() < retry_at) {
+            return;
+        }
+        self.personal_fm_refill_retry_at = None;
+
+        if self.personal_fm_buffer.len() < PERSONAL_FM_BUFFER_LOW {
+            trace!("refill personal fm, {} songs left", self.personal_fm_buffer.len());
+            self.personal_fm_refill = Some(tokio::spawn(ncm_client.personal_fm_loader().load()));
+        }
+    }
+
+    /// 私人 FM 模式下没有待播歌曲时立即获取（等待正在进行的补充，或直接请求）
+    ///
+    /// 获取失败时停止播放，避免每次 auto_play 都重新请求
+    async fn ensure_personal_fm_buffer(&mut self, ncm_client: &NcmClient) -> Result<()> {
+        if self.play_mode != PlayMode::PersonalFm || !self.personal_fm_buffer.is_empty() {
+            return Ok(());
+        }
+
+        let songs = match self.personal_fm_refill.take() {
+            Some(personal_fm_refill) => match personal_fm_refill.await {
+                Ok(result) => result,
+                // 补充任务异常退出时直接请求
+                Err(_) => ncm_client.personal_fm_loader().load().await,
+            },
+            None => ncm_client.personal_fm_loader().load().await,
+        };
+
+        match songs {
+            Ok(songs) => {
+                self.personal_fm_buffer.extend(songs);
+                Ok(())
+            },
+            Err(e) => {
+                self.play_state = PlayState::Stopped;
+                Err(e.into())
+            },
+        }
+    }
+}
+
+/// 播客
+impl Player {
+    /// 切换到电台节目组成的播放列表，播放时从各节目上次的位置继续
+    pub fn switch_to_podcast(&mut self, dj_radio: &DjRadio, programs: &[Program]) {
+        self.switch_to_songlist(&dj_radio.as_songlist(programs));
+        self.is_podcast = true;
+    }
+
+    /// 更换保存播放位置的文件（切换账号配置时调用），保存当前的播放位置后读取新文件中的播放位置
+    pub fn set_program_positions_path(&mut self, program_positions_path: PathBuf) {
+        self.store_program_positions();
+
+        self.program_positions = read_program_positions(&program_positions_path);
+        self.program_positions_path = program_positions_path;
+        self.program_positions_changed = false;
+    }
+
+    /// 将各节目的播放位置写入磁盘（退出时调用）
+    pub fn store_program_positions(&mut self) {
+        self.program_positions_stored_at = Instant::now();
+        if !self.program_positions_changed {
+            return;
+        }
+
+        let content: String = self.program_positions.iter().map(|(song_id, position)| format!("{} {}\n", song_id, position)).collect();
+        match fs::write(&self.program_positions_path, content) {
+            Ok(_) => {
+                self.program_positions_changed = false;
+                trace!("stored positions of {} programs", self.program_positions.len());
+            },
+            Err(e) => error!("failed to store program positions: {:?}", e),
+        }
+    }
+
+    /// 记录当前节目的播放位置，播放完的节目下次从头播放
+    fn record_program_position(&mut self) {
+        if let (Some(current_song), Some(position)) = (self.current_song.as_ref(), self.position()) {
+            match self.play_state {
+                PlayState::Playing | PlayState::Paused => {
+                    if self.program_positions.insert(current_song.id, position.mseconds()) != Some(position.mseconds()) {
+                        self.program_positions_changed = true;
+                    }
+                },
+                PlayState::Ended => {
+                    if self.program_positions.remove(&current_song.id).is_some() {
+                        self.program_positions_changed = true;
+                    }
+                },
+                PlayState::Stopped => {},
+            }
+        }
+
+        if self.program_positions_stored_at.elapsed() >= PROGRAM_POSITIONS_STORE_INTERVAL {
+            self.store_program_positions();
+        }
+    }
+}
+
+/// 读取各节目的播放位置，每行为 `<节目音频的歌曲 id> <播放位置（ms）>`
+fn read_program_positions(path: &PathBuf) -> HashMap<u64, u64> {
+    match fs::read_to_string(path) {
+        Ok(content) => content
+            .lines()
+            .filter_map(|line| {
+                let (song_id, position) = line.split_once(' ')?;
+                Some((song_id.parse().ok()?, position.parse().ok()?))
+            })
+            .collect(),
+        Err(e) => {
+            debug!("no program positions read: {:?}", e);
+            HashMap::new()
+        },
+    }
+}
+
+#[inline]
+fn apply_songs_availability(songs: &mut [Song], songs_availability: &HashMap<u64, SongAvailability>) {
+    for song in songs.iter_mut() {
+        if let Some(availability) = songs_availability.get(&song.id) {
+            song.availability = *availability;
+        }
+    }
+}
+
 /// private
 impl Player {
+    /// 实际生效的播放模式：心动模式和播客按列表顺序播放，随机播放视为列表循环
+    fn effective_play_mode(&self) -> PlayMode {
+        match self.play_mode {
+            PlayMode::Shuffle if self.is_heart_mode || self.is_podcast => PlayMode::ListRepeat,
+            ref play_mode => play_mode.clone(),
+        }
+    }
+
     /// 根据模式更新下一首播放的歌曲
     /// 更新 self.current_song & self.current_song_index
     fn update_next_to_play(&mut self) {
-        self.current_song = match self.play_mode {
+        // 优先播放待播队列中的歌曲
+        if let Some(index) = self.play_queue.pop_front() {
+            if let Some(song) = self.current_playlist.get(index) {
+                self.current_song_index = Some(index);
+                self.current_song = Some(song.clone());
+                return;
+            }
+        }
+
+        self.current_song = match self.effective_play_mode() {
             PlayMode::Single => None,
             PlayMode::SingleRepeat => self.current_song.clone(),
             PlayMode::ListRepeat => {
@@ -365,27 +1099,66 @@ impl Player {
                 }
             },
             PlayMode::Shuffle => {
-                if let Some(mut index) = self.current_song_index {
-                    index = thread_rng().gen_range(0..self.current_playlist.len());
+                if self.current_song_index.is_some() && !self.current_playlist.is_empty() {
+                    let index = self.next_shuffle_index();
                     self.current_song_index = Some(index);
                     Some(self.current_playlist[index].clone())
                 } else {
                     None
                 }
             },
+            PlayMode::PersonalFm => match self.personal_fm_buffer.pop_front() {
+                Some(mut song) => {
+                    song.availability = self.song_availability(song.id);
+                    self.current_playlist.push(song.clone());
+                    self.current_playlist_revision += 1;
+                    self.current_song_index = Some(self.current_playlist.len() - 1);
+                    self.trim_personal_fm_playlist();
+                    Some(song)
+                },
+                None => None,
+            },
         };
     }
 
+    /// 随机播放模式下的下一首，优先使用预先抽取的 index
+    fn next_shuffle_index(&mut self) -> usize {
+        let len = self.current_playlist.len();
+
+        match self.shuffle_order.pop_front() {
+            Some(index) if index < len => index,
+            _ => thread_rng().gen_range(0..len),
+        }
+    }
+
     /// 播放下一首
     async fn play_next<'c>(&mut self, ncm_client_guard: MutexGuard<'c, NcmClient>) -> Result<()> {
         if let Some(mut song) = self.current_song.clone() {
-            // 检查歌曲是否可获取（版权/会员/...限制）
-            if ncm_client_guard.check_song_availability(song.id).await? {
-                // 获取歌曲 uri
-                ncm_client_guard.load_song_url(&mut song).await?;
+            // 获取歌曲链接，优先使用预先获取且未过期的链接
+            // 获取不到链接（版权/会员/...限制）的歌曲视为不可获取
+            // api 返回错误码或无法解析的数据时也视为不可获取；网络异常等其他错误交给上层处理
+            let song_url = match self.song_urls.remove(&song.id).filter(|song_url| !song_url.is_expired()) {
+                Some(song_url) => Some(song_url),
+                None => match ncm_client_guard.load_song_urls(std::slice::from_ref(&song)).await {
+                    Ok(mut song_urls) => song_urls.remove(&song.id),
+                    Err(NcmError::ApiCode { .. } | NcmError::MalformedResponse(_)) => None,
+                    Err(e) => {
+                        self.play_state = PlayState::Stopped;
+                        return Err(e.into());
+                    },
+                },
+            };
+
+            if let Some(song_url) = song_url.as_ref() {
+                self.record_songs_availability(HashMap::from([(song.id, song_url.availability)]));
+            }
 
+            if let Some(song_url) = song_url.filter(SongUrl::is_available) {
                 // 更新当前歌曲信息
+                song.set_song_url(&song_url);
+                song.availability = song_url.availability;
                 self.current_song = Some(song.clone());
+                self.current_song_url = Some(song_url);
 
                 if let Some(url) = song.song_url {
                     // 入栈播放历史
@@ -399,12 +1172,25 @@ impl Player {
                     // 播放
                     self.play_new_song_by_uri(url.as_str()).await;
 
+                    // 播客节目从上次的位置继续播放
+                    if self.is_podcast {
+                        if let Some(position) = self.program_positions.get(&song.id) {
+                            self.play.seek(ClockTime::from_mseconds(*position));
+                        }
+                    }
+
                     // 播放状态
                     self.play_state = PlayState::Playing;
 
                     debug!("play next song: {:?}", self.current_song);
                 }
             } else {
+                debug!("skip unavailable song: {:?}", song);
+
+                // 记录跳过的歌曲，由界面提示
+                song.availability = self.song_availability(song.id);
+                self.skipped_songs.push(song);
+
                 // 更新播放状态为 Ended ，以便继续寻找下一首
                 self.play_state = PlayState::Ended;
             }
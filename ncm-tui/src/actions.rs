@@ -1,18 +1,328 @@
-use crate::{ncm_client, player};
-use anyhow::Result;
+use crate::config::Profile;
+use crate::{ncm_client, path_config, player};
+use anyhow::{anyhow, Result};
+use log::{debug, error};
+use ncm_api::model::{Quality, Song, Songlist};
+use ncm_api::UserSonglistsPages;
+use std::fs;
+use tokio::task;
 
+/// 获取用户歌单的第一页并切换到第一个歌单，其余页在后台继续获取
 pub async fn init_songlists() -> Result<()> {
+    // 获取期间不持有 ncm_client 的锁
+    let mut pages = ncm_client.lock().await.user_songlists_pages()?;
+    if let Ok(Some(songlists)) = pages.next_page().await {
+        let first_songlist = songlists.first().cloned();
+        player.lock().await.set_songlists(songlists);
+
+        if pages.has_more() {
+            task::spawn(load_remaining_songlists(pages));
+        }
+
+        // 同样在不持有锁时装载第一个歌单的歌曲
+        if let Some(mut songlist) = first_songlist {
+            let loader = ncm_client.lock().await.songlist_songs_loader(&songlist);
+            songlist.songs = loader.load(|_, _| {}).await?;
+
+            player.lock().await.switch_playlist(&songlist);
+        }
+    }
+
+    Ok(())
+}
+
+/// 在后台逐页获取剩余的用户歌单，每获取一页就追加到播放器中，songlists_panel 随之刷新
+async fn load_remaining_songlists(mut pages: UserSonglistsPages) {
+    loop {
+        // 获取期间不持有锁，只在合并结果时加锁，两页之间界面可以正常刷新
+        match pages.next_page().await {
+            Ok(Some(songlists)) => {
+                let mut player_guard = player.lock().await;
+                // 获取期间已登出或切换账号，丢弃后续结果
+                if ncm_client.lock().await.login_account().map(|account| account.user_id) != Some(pages.user_id()) {
+                    break;
+                }
+                player_guard.append_songlists(songlists);
+            },
+            Ok(None) => break,
+            Err(e) => {
+                error!("failed to load songlists at offset {}: {:?}", pages.offset(), e);
+                break;
+            },
+        }
+    }
+
+    debug!("finished loading songlists, next offset: {}", pages.offset());
+}
+
+/// 重新获取用户歌单（歌单增删改后调用），songlists_panel 随之刷新
+pub async fn refresh_songlists() -> Result<()> {
+    // 逐页获取期间不持有 ncm_client 的锁
+    let mut pages = ncm_client.lock().await.user_songlists_pages()?;
+    let mut songlists = Vec::new();
+    while let Some(page) = pages.next_page().await? {
+        songlists.extend(page);
+    }
+
+    player.lock().await.set_songlists(songlists);
+
+    Ok(())
+}
+
+/// 当前播放的用户歌单（当前播放列表不是用户歌单时为 None）
+pub async fn current_songlist() -> Option<Songlist> {
+    let player_guard = player.lock().await;
+    let current_playlist_id = player_guard.current_playlist_id()?;
+
+    player_guard.songlists().iter().find(|songlist| songlist.id == current_playlist_id).cloned()
+}
+
+/// 新建歌单
+///
+/// 返回需要在命令行显示的提示
+pub async fn create_songlist(name: &str) -> Result<String> {
+    ncm_client.lock().await.create_songlist(name).await?;
+    refresh_songlists().await?;
+
+    Ok(format!("已新建歌单`{}`", name))
+}
+
+/// 重命名歌单
+///
+/// 返回需要在命令行显示的提示
+pub async fn rename_songlist(songlist: &Songlist, name: &str) -> Result<String> {
+    check_own_songlist(songlist).await?;
+    ncm_client.lock().await.rename_songlist(songlist.id, name).await?;
+    refresh_songlists().await?;
+
+    Ok(format!("已将歌单`{}`重命名为`{}`", songlist.name, name))
+}
+
+/// 删除歌单
+///
+/// 返回需要在命令行显示的提示
+pub async fn delete_songlist(songlist: &Songlist) -> Result<String> {
+    check_own_songlist(songlist).await?;
+    ncm_client.lock().await.delete_songlist(songlist.id).await?;
+    refresh_songlists().await?;
+
+    Ok(format!("已删除歌单`{}`", songlist.name))
+}
+
+/// 收藏/取消收藏歌单
+///
+/// 返回需要在命令行显示的提示
+pub async fn subscribe_songlist(songlist: &Songlist, subscribe: bool) -> Result<String> {
     let ncm_client_guard = ncm_client.lock().await;
+    if ncm_client_guard.is_own_songlist(songlist) {
+        return Err(anyhow!("不能收藏自己创建的歌单"));
+    }
+
+    ncm_client_guard.subscribe_songlist(songlist.id, subscribe).await?;
+    drop(ncm_client_guard);
+    refresh_songlists().await?;
+
+    if subscribe {
+        Ok(format!("已收藏歌单`{}`", songlist.name))
+    } else {
+        Ok(format!("已取消收藏歌单`{}`", songlist.name))
+    }
+}
+
+/// 只有自己创建的歌单可以修改
+async fn check_own_songlist(songlist: &Songlist) -> Result<()> {
+    if ncm_client.lock().await.is_own_songlist(songlist) {
+        Ok(())
+    } else {
+        Err(anyhow!("只能修改自己创建的歌单`{}`", songlist.name))
+    }
+}
+
+/// 将歌曲添加到指定名称的用户歌单，只能添加到自己创建的歌单
+///
+/// 返回需要在命令行显示的提示
+pub async fn add_song_to_songlist(song: &Song, songlist_name: &str) -> Result<String> {
+    let (own_songlist, has_songlist) = {
+        // 与其他地方一致，先锁 player 再锁 ncm_client ，避免死锁
+        let player_guard = player.lock().await;
+        let ncm_client_guard = ncm_client.lock().await;
+        let mut candidates = player_guard.songlists().iter().filter(|songlist| songlist.name == songlist_name).peekable();
+        let has_songlist = candidates.peek().is_some();
+        let own_songlist = candidates.find(|songlist| ncm_client_guard.is_own_songlist(songlist)).cloned();
+        (own_songlist, has_songlist)
+    };
+
+    match own_songlist {
+        Some(songlist) => {
+            ncm_client.lock().await.add_songs_to_songlist(songlist.id, &[song.id]).await?;
+            refresh_songlists().await?;
+
+            Ok(format!("已将`{}`添加到歌单`{}`", song.name, songlist.name))
+        },
+        // 同名歌单都是收藏的
+        None if has_songlist => Err(anyhow!("只能修改自己创建的歌单`{}`", songlist_name)),
+        None => Err(anyhow!("没有名为`{}`的歌单", songlist_name)),
+    }
+}
+
+/// 将歌曲从歌单中移除
+///
+/// 返回需要在命令行显示的提示
+pub async fn remove_song_from_songlist(song: &Song, songlist: &Songlist) -> Result<String> {
+    check_own_songlist(songlist).await?;
+    ncm_client.lock().await.remove_songs_from_songlist(songlist.id, &[song.id]).await?;
+    refresh_songlists().await?;
+
+    Ok(format!("已将`{}`从歌单`{}`中移除", song.name, songlist.name))
+}
+
+pub async fn init_liked_songs() -> Result<()> {
+    Ok(ncm_client.lock().await.load_liked_song_ids().await?)
+}
+
+/// 喜欢/取消喜欢当前播放的歌曲，`like` 为 None 时切换喜欢状态
+///
+/// 返回需要在命令行显示的提示
+pub async fn like_current_song(like: Option<bool>) -> Result<String> {
+    let current_song = player.lock().await.current_song().clone();
+
+    if let Some(song) = current_song {
+        let mut ncm_client_guard = ncm_client.lock().await;
+        let like = like.unwrap_or(!ncm_client_guard.is_liked(song.id));
+
+        ncm_client_guard.like_song(song.id, like).await?;
+
+        if like {
+            Ok(format!("已喜欢`{}`", song.name))
+        } else {
+            Ok(format!("已取消喜欢`{}`", song.name))
+        }
+    } else {
+        Err(anyhow!("当前没有正在播放的歌曲"))
+    }
+}
+
+/// 私人 FM 模式下将当前歌曲移入垃圾桶，并播放下一首
+///
+/// 返回需要在命令行显示的提示
+pub async fn trash_current_song() -> Result<String> {
+    let song = player.lock().await.trash_current_song(ncm_client.lock().await).await?;
+
+    Ok(format!("已将`{}`移入垃圾桶，不再推荐", song.name))
+}
+
+/// 以当前歌曲为起点进入心动模式，推荐的歌曲替换当前播放列表
+///
+/// 返回需要在命令行显示的提示
+pub async fn start_heart_mode() -> Result<String> {
+    let (current_song, liked_songlist) = {
+        // 与其他地方一致，先锁 player 再锁 ncm_client ，避免死锁
+        let player_guard = player.lock().await;
+        let ncm_client_guard = ncm_client.lock().await;
+        // 用户歌单中的第一个自建歌单即`我喜欢的音乐`
+        let liked_songlist = player_guard.songlists().iter().find(|songlist| ncm_client_guard.is_own_songlist(songlist)).cloned();
+        (player_guard.current_song().clone(), liked_songlist)
+    };
+
+    let current_song = match current_song {
+        Some(current_song) => current_song,
+        None => return Err(anyhow!("当前没有正在播放的歌曲")),
+    };
+    let liked_songlist = match liked_songlist {
+        Some(liked_songlist) => liked_songlist,
+        None => return Err(anyhow!("心动模式需要登录后使用")),
+    };
+
+    let songs = ncm_client.lock().await.get_heart_mode_songs(current_song.id, liked_songlist.id).await?;
+    let songs_count = songs.iter().filter(|song| song.id != current_song.id).count();
+    player.lock().await.start_heart_mode(songs)?;
+
+    Ok(format!("已从`{}`进入心动模式，推荐了{}首歌曲", current_song.name, songs_count))
+}
+
+/// 当前歌曲（无正在播放的歌曲时为设置中）的首选音质
+pub async fn current_quality() -> String {
+    let current_song_id = player.lock().await.current_song().as_ref().map(|song| song.id).unwrap_or(0);
+
+    format!("首选音质：{}", ncm_client.lock().await.preferred_quality(current_song_id))
+}
+
+/// 设置首选音质，`for_current_song` 为 true 时只对当前歌曲生效并立即以新音质重新播放
+///
+/// 返回需要在命令行显示的提示
+pub async fn set_quality(quality: Option<Quality>, for_current_song: bool) -> Result<String> {
+    // 已预先获取的链接按原音质获取，需要重新获取
+    player.lock().await.clear_song_urls();
+
+    if !for_current_song {
+        ncm_client.lock().await.set_quality_override(quality);
+
+        return Ok(match quality {
+            Some(quality) => format!("首选音质已设为{}（本次运行期间有效）", quality),
+            None => String::from("已恢复设置中的首选音质"),
+        });
+    }
+
     let mut player_guard = player.lock().await;
-    if let Ok(songlists) = ncm_client_guard.get_user_all_songlists().await {
-        let len = songlists.len();
+    let (current_song, current_song_index) = match (player_guard.current_song().clone(), player_guard.current_song_index()) {
+        (Some(current_song), Some(current_song_index)) => (current_song, current_song_index),
+        _ => return Err(anyhow!("当前没有正在播放的歌曲")),
+    };
+
+    let mut ncm_client_guard = ncm_client.lock().await;
+    ncm_client_guard.set_song_quality_override(current_song.id, quality);
+    let preferred_quality = ncm_client_guard.preferred_quality(current_song.id);
+
+    // 以新音质重新获取歌曲链接并播放
+    player_guard.play_particularly_now(current_song_index, ncm_client_guard).await?;
+
+    Ok(format!("`{}`的首选音质已设为{}", current_song.name, preferred_quality))
+}
 
-        player_guard.set_songlists(songlists);
+/// 登出当前账号，清空播放器中的歌单，`purge_cache` 为 true 时同时清除本地缓存
+pub async fn logout(purge_cache: bool) -> Result<()> {
+    ncm_client.lock().await.logout().await?;
 
-        if len > 0 {
-            player_guard.switch_playlist(0, ncm_client_guard).await?;
+    player.lock().await.reset();
+
+    if purge_cache {
+        let current_profile = Profile::current(&path_config);
+
+        for entry in fs::read_dir(&current_profile.cache)? {
+            let entry_path = entry?.path();
+            // 默认账号配置的缓存目录中还有其他账号配置的缓存
+            if entry_path == path_config.profiles_cache {
+                continue;
+            }
+
+            if entry_path.is_dir() {
+                fs::remove_dir_all(&entry_path)?;
+            } else {
+                fs::remove_file(&entry_path)?;
+            }
         }
+
+        // 重建歌词缓存目录
+        fs::create_dir_all(&current_profile.lyrics)?;
     }
 
     Ok(())
 }
+
+/// 切换到指定账号配置并尝试 cookie 登录，返回是否登录成功
+pub async fn switch_profile(name: &str) -> Result<bool> {
+    if !Profile::exists(&path_config, name) {
+        return Err(anyhow!("账号配置`{}`不存在，使用`profile add {}`新建", name, name));
+    }
+
+    let profile = Profile::load(&path_config, name);
+    profile.set_as_current(&path_config)?;
+
+    // 播客的播放位置同样属于账号配置
+    let mut player_guard = player.lock().await;
+    player_guard.reset();
+    player_guard.set_program_positions_path(profile.program_positions);
+    drop(player_guard);
+
+    Ok(ncm_client.lock().await.switch_profile(profile.login_cookie, profile.lyrics, profile.settings_override).await?)
+}
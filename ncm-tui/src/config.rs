@@ -1,10 +1,12 @@
 mod command;
 mod logo;
 mod path;
+mod profile;
 pub mod style;
 mod ui;
 
 pub use command::*;
 pub use logo::*;
 pub use path::*;
+pub use profile::*;
 pub use ui::*;
@@ -1,6 +1,7 @@
 use crate::config::Command::SwitchPlayMode;
 use crate::config::ScreenEnum;
 use anyhow::{anyhow, Result};
+use ncm_api::model::{Quality, Song};
 use ncm_play::PlayMode;
 
 #[derive(Clone, Debug)]
@@ -8,15 +9,76 @@ pub enum Command {
     Quit,
     GotoScreen(ScreenEnum),
     EnterCommand,
-    Logout,
+    /// 登出，参数为是否同时清除缓存
+    Logout(bool),
+    /// 列出所有账号配置
+    ListProfiles,
+    /// 切换到指定账号配置
+    SwitchProfile(String),
+    /// 新建账号配置并切换过去
+    AddProfile(String),
+    /// 删除指定账号配置
+    RemoveProfile(String),
     PlayOrPause,
     SetVolume(f64),
     SwitchPlayMode(PlayMode),
+    /// 显示当前首选音质
+    ShowQuality,
+    /// 设置首选音质，参数为音质（None 时恢复设置中的首选音质）和是否只对当前歌曲生效
+    SetQuality(Option<Quality>, bool),
     StartPlay,
     NextSong,
+    /// 私人 FM 模式下将当前歌曲移入垃圾桶并播放下一首
+    TrashSong,
+    /// 以当前歌曲为起点进入心动模式
+    HeartMode,
     PrevSong,
     SearchForward(Vec<String>),
     SearchBackward(Vec<String>),
+    /// 喜欢当前歌曲
+    Like,
+    /// 取消喜欢当前歌曲
+    Unlike,
+    /// 切换当前歌曲的喜欢状态
+    ToggleLike,
+    /// 全局搜索（歌曲/专辑/歌手/歌单）
+    GlobalSearch(Vec<String>),
+    /// 查看所选歌曲（或当前播放歌曲）所属的专辑
+    ShowAlbum,
+    /// 打开指定 id 的专辑页面
+    OpenAlbum(u64),
+    /// 查看所选歌曲（或当前播放歌曲）的歌手主页，参数为多位歌手时选择的歌手序号（从 0 开始）
+    ShowArtist(Option<usize>),
+    /// 打开指定 id 的歌手主页
+    OpenArtist(u64),
+    /// 查看所选歌曲（或当前播放歌曲）的评论
+    ShowComments,
+    /// 打开指定歌曲的评论页面
+    OpenComments(Box<Song>),
+    /// 给评论页面中所选的评论点赞或取消点赞
+    ToggleLikeComment,
+    /// 将所选内容加入待播队列
+    Enqueue,
+    /// 新建歌单
+    CreateSonglist(String),
+    /// 重命名所选歌单（或当前播放的歌单）
+    RenameSonglist(String),
+    /// 删除所选歌单（或当前播放的歌单）
+    DeleteSonglist,
+    /// 将所选歌曲添加到指定名称的歌单
+    AddToSonglist(String),
+    /// 将所选歌曲从其所在的歌单中移除
+    RemoveFromSonglist,
+    /// 收藏所选歌单（或当前播放的歌单）
+    Subscribe,
+    /// 取消收藏所选歌单（或当前播放的歌单）
+    Unsubscribe,
+    /// 将所选歌曲在歌单中上移一位
+    MoveSongUp,
+    /// 将所选歌曲在歌单中下移一位
+    MoveSongDown,
+    /// 将所选歌曲移动到歌单中的指定位置（从 0 开始）
+    MoveSongTo(usize),
     RefreshPlaylist,
 
     Down,
@@ -44,13 +106,32 @@ impl Command {
             Some("screen") => match tokens.next() {
                 Some("1" | "main") => Ok(Self::GotoScreen(ScreenEnum::Main)),
                 Some("2" | "playlist" | "playlists") => Ok(Self::GotoScreen(ScreenEnum::Songlists)),
+                Some("3" | "search") => Ok(Self::GotoScreen(ScreenEnum::Search)),
+                Some("4" | "recommend") => Ok(Self::GotoScreen(ScreenEnum::Recommend)),
+                Some("5" | "toplist" | "charts") => Ok(Self::GotoScreen(ScreenEnum::Toplists)),
+                Some("6" | "podcast" | "dj") => Ok(Self::GotoScreen(ScreenEnum::Podcast)),
+                Some("7" | "history" | "recent") => Ok(Self::GotoScreen(ScreenEnum::History)),
+                Some("8" | "user" | "me") => Ok(Self::GotoScreen(ScreenEnum::Profile)),
                 Some("0" | "help") => Ok(Self::GotoScreen(ScreenEnum::Help)),
                 Some(other) => Err(anyhow!("screen: Invalid screen identifier: {}", other)),
                 None => Err(anyhow!("screen: Missing argument SCREEN_ID")),
             },
             Some("h" | "help") => Ok(Self::GotoScreen(ScreenEnum::Help)),
             Some("l" | "login") => Ok(Self::GotoScreen(ScreenEnum::Login)),
-            Some("logout") => Ok(Self::Logout),
+            Some("logout") => match tokens.next() {
+                Some("-p" | "--purge") => Ok(Self::Logout(true)),
+                Some(other) => Err(anyhow!("logout: Invalid argument '{}'", other)),
+                None => Ok(Self::Logout(false)),
+            },
+            Some("profile") => match (tokens.next(), tokens.next()) {
+                (Some("list" | "ls"), _) => Ok(Self::ListProfiles),
+                (Some("switch"), Some(name)) => Ok(Self::SwitchProfile(name.to_string())),
+                (Some("add"), Some(name)) => Ok(Self::AddProfile(name.to_string())),
+                (Some("remove" | "rm"), Some(name)) => Ok(Self::RemoveProfile(name.to_string())),
+                (Some("switch" | "add" | "remove" | "rm"), None) => Err(anyhow!("profile: Missing argument PROFILE_NAME")),
+                (Some(other), _) => Err(anyhow!("profile: Invalid argument '{}'", other)),
+                (None, _) => Err(anyhow!("profile: Missing argument list/switch/add/remove")),
+            },
             Some("vol" | "volume") => match tokens.next() {
                 Some(num) => {
                     if let Ok(vol) = num.parse::<f64>() {
@@ -67,12 +148,79 @@ impl Command {
                 Some("sr" | "single-repeat") => Ok(SwitchPlayMode(PlayMode::SingleRepeat)),
                 Some("lr" | "list-repeat") => Ok(SwitchPlayMode(PlayMode::ListRepeat)),
                 Some("s" | "shuf" | "shuffle") => Ok(SwitchPlayMode(PlayMode::Shuffle)),
+                Some("fm" | "personal-fm") => Ok(SwitchPlayMode(PlayMode::PersonalFm)),
                 Some(other) => Err(anyhow!("switch: Invalid play mode identifier: {}", other)),
                 None => Err(anyhow!("switch: Missing argument PLAY_MODE")),
             },
+            Some("quality") => {
+                let quality = match tokens.next() {
+                    Some("reset" | "default") => None,
+                    Some(level) => match level.parse::<Quality>() {
+                        Ok(quality) => Some(quality),
+                        Err(_) => {
+                            let levels: Vec<&str> = Quality::ALL.iter().map(|quality| quality.level()).collect();
+                            return Err(anyhow!("quality: Invalid quality level '{}' ({} / reset)", level, levels.join(" / ")));
+                        },
+                    },
+                    None => return Ok(Self::ShowQuality),
+                };
+
+                match tokens.next() {
+                    Some("-s" | "--song") => Ok(Self::SetQuality(quality, true)),
+                    Some(other) => Err(anyhow!("quality: Invalid argument '{}'", other)),
+                    None => Ok(Self::SetQuality(quality, false)),
+                }
+            },
             Some("next") => Ok(Self::NextSong),
             Some("prev" | "previous") => Ok(Self::PrevSong),
             Some("start") => Ok(Self::StartPlay),
+            Some("like") => Ok(Self::Like),
+            Some("unlike") => Ok(Self::Unlike),
+            Some("album") => Ok(Self::ShowAlbum),
+            Some("artist") => match tokens.next() {
+                Some(num) => match num.parse::<usize>() {
+                    Ok(index) if index > 0 => Ok(Self::ShowArtist(Some(index - 1))),
+                    _ => Err(anyhow!("artist: Invalid argument NUMBER")),
+                },
+                None => Ok(Self::ShowArtist(None)),
+            },
+            Some("comments" | "comment") => Ok(Self::ShowComments),
+            Some("likecomment") => Ok(Self::ToggleLikeComment),
+            Some("enqueue") => Ok(Self::Enqueue),
+            Some("playlist") => {
+                let action = tokens.next();
+                let name = tokens.collect::<Vec<&str>>().join(" ");
+                match (action, name.is_empty()) {
+                    (Some("new"), false) => Ok(Self::CreateSonglist(name)),
+                    (Some("rename"), false) => Ok(Self::RenameSonglist(name)),
+                    (Some("new" | "rename"), true) => Err(anyhow!("playlist: Missing argument PLAYLIST_NAME")),
+                    (Some("delete" | "rm"), _) => Ok(Self::DeleteSonglist),
+                    (Some(other), _) => Err(anyhow!("playlist: Invalid argument '{}'", other)),
+                    (None, _) => Err(anyhow!("playlist: Missing argument new/rename/delete")),
+                }
+            },
+            Some("add-to") => {
+                let name = tokens.collect::<Vec<&str>>().join(" ");
+                if name.is_empty() {
+                    Err(anyhow!("add-to: Missing argument PLAYLIST_NAME"))
+                } else {
+                    Ok(Self::AddToSonglist(name))
+                }
+            },
+            Some("trash") => Ok(Self::TrashSong),
+            Some("heartmode" | "heart") => Ok(Self::HeartMode),
+            Some("remove") => Ok(Self::RemoveFromSonglist),
+            Some("subscribe") => Ok(Self::Subscribe),
+            Some("unsubscribe") => Ok(Self::Unsubscribe),
+            Some("move") => match tokens.next() {
+                Some("up") => Ok(Self::MoveSongUp),
+                Some("down") => Ok(Self::MoveSongDown),
+                Some(num) => match num.parse::<usize>() {
+                    Ok(position) if position > 0 => Ok(Self::MoveSongTo(position - 1)),
+                    _ => Err(anyhow!("move: Invalid argument '{}'", num)),
+                },
+                None => Err(anyhow!("move: Missing argument up/down/NUMBER")),
+            },
             Some("where") => match tokens.next() {
                 Some("this") => Ok(Self::WhereIsThisSong),
                 Some(other) => Err(anyhow!("where: Invalid argument '{}'", other)),
@@ -80,6 +228,14 @@ impl Command {
             },
             Some("top") => Ok(Self::GoToTop),
             Some("bottom") => Ok(Self::GoToBottom),
+            Some("search") => {
+                let keywords: Vec<String> = tokens.map(|keyword| keyword.to_string()).collect();
+                if keywords.is_empty() {
+                    Err(anyhow!("search: Missing argument KEYWORDS"))
+                } else {
+                    Ok(Self::GlobalSearch(keywords))
+                }
+            },
             Some("/") => {
                 let mut keywords = Vec::new();
                 while let Some(keyword) = tokens.next() {
@@ -15,6 +15,10 @@ pub struct Path {
     pub settings: PathBuf,
     pub login_cookie: PathBuf,
     pub lyrics: PathBuf,
+    pub program_positions: PathBuf,
+    pub profiles: PathBuf,
+    pub profiles_cache: PathBuf,
+    pub current_profile: PathBuf,
 }
 
 impl Path {
@@ -45,6 +49,17 @@ impl Path {
             fs::create_dir_all(&lyrics).expect("Couldn't create lyrics dir.");
         }
 
+        let program_positions = data.clone().join("program_positions");
+
+        let profiles = data.clone().join("profiles");
+        if !profiles.exists() {
+            fs::create_dir_all(&profiles).expect("Couldn't create profiles dir.");
+        }
+
+        let profiles_cache = cache.clone().join("profiles");
+
+        let current_profile = data.clone().join("current_profile");
+
         Self {
             data,
             config,
@@ -53,6 +68,10 @@ impl Path {
             settings,
             login_cookie,
             lyrics,
+            program_positions,
+            profiles,
+            profiles_cache,
+            current_profile,
         }
     }
 }
@@ -0,0 +1,129 @@
+use crate::config::Path;
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::PathBuf;
+
+/// 默认账号配置，沿用数据目录和缓存目录下原有的 cookie 、设置和缓存
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
+/// 账号配置，每个账号配置拥有独立的 cookie 、设置覆盖、播客播放位置和缓存
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub name: String,
+    //
+    pub login_cookie: PathBuf,
+    pub settings_override: Option<PathBuf>,
+    pub program_positions: PathBuf,
+    pub cache: PathBuf,
+    pub lyrics: PathBuf,
+}
+
+impl Profile {
+    /// 根据名称获取账号配置的各路径，并确保缓存目录存在
+    pub fn load(path: &Path, name: &str) -> Self {
+        let profile = if name == DEFAULT_PROFILE_NAME {
+            Self {
+                name: name.to_string(),
+                login_cookie: path.login_cookie.clone(),
+                settings_override: None,
+                program_positions: path.program_positions.clone(),
+                cache: path.cache.clone(),
+                lyrics: path.lyrics.clone(),
+            }
+        } else {
+            let data = path.profiles.join(name);
+            let cache = path.profiles_cache.join(name);
+
+            Self {
+                name: name.to_string(),
+                login_cookie: data.join("cookies"),
+                settings_override: Some(data.join("settings.json")),
+                program_positions: data.join("program_positions"),
+                lyrics: cache.join("lyrics"),
+                cache,
+            }
+        };
+
+        if !profile.lyrics.exists() {
+            fs::create_dir_all(&profile.lyrics).expect("Couldn't create lyrics dir.");
+        }
+
+        profile
+    }
+
+    /// 当前使用的账号配置（记录的账号配置不存在时使用默认账号配置）
+    pub fn current(path: &Path) -> Self {
+        match fs::read_to_string(&path.current_profile) {
+            Ok(name) if Self::exists(path, name.trim()) => Self::load(path, name.trim()),
+            _ => Self::load(path, DEFAULT_PROFILE_NAME),
+        }
+    }
+
+    /// 记录为当前使用的账号配置
+    pub fn set_as_current(&self, path: &Path) -> Result<()> {
+        fs::write(&path.current_profile, &self.name)?;
+
+        Ok(())
+    }
+
+    /// 所有账号配置的名称，默认账号配置排在最前
+    pub fn list(path: &Path) -> Vec<String> {
+        let mut names: Vec<String> = match fs::read_dir(&path.profiles) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        names.sort();
+        names.insert(0, DEFAULT_PROFILE_NAME.to_string());
+
+        names
+    }
+
+    pub fn exists(path: &Path, name: &str) -> bool {
+        name == DEFAULT_PROFILE_NAME || (is_valid_profile_name(name) && path.profiles.join(name).is_dir())
+    }
+
+    /// 新建账号配置
+    pub fn create(path: &Path, name: &str) -> Result<Self> {
+        if !is_valid_profile_name(name) {
+            return Err(anyhow!("profile: Invalid profile name '{}' (only letters, digits, `-` and `_` are allowed)", name));
+        }
+        if Self::exists(path, name) {
+            return Err(anyhow!("profile: Profile '{}' already exists", name));
+        }
+
+        fs::create_dir_all(path.profiles.join(name))?;
+
+        Ok(Self::load(path, name))
+    }
+
+    /// 删除账号配置及其 cookie 、设置覆盖、播客播放位置和缓存，默认账号配置和当前使用的账号配置不可删除
+    pub fn remove(path: &Path, name: &str) -> Result<()> {
+        if name == DEFAULT_PROFILE_NAME {
+            return Err(anyhow!("profile: The default profile can't be removed"));
+        }
+        if !Self::exists(path, name) {
+            return Err(anyhow!("profile: Profile '{}' doesn't exist", name));
+        }
+        if Self::current(path).name == name {
+            return Err(anyhow!("profile: Profile '{}' is in use, switch to another profile first", name));
+        }
+
+        fs::remove_dir_all(path.profiles.join(name))?;
+
+        let cache = path.profiles_cache.join(name);
+        if cache.exists() {
+            fs::remove_dir_all(cache)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
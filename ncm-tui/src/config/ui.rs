@@ -10,6 +10,15 @@ pub enum AppMode {
 pub enum ScreenEnum {
     Main,
     Songlists,
+    Search,
+    Recommend,
+    Toplists,
+    Podcast,
+    History,
+    Profile,
+    Album,
+    Artist,
+    Comments,
     Login,
     Help,
     Launch,
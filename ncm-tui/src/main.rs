@@ -2,7 +2,7 @@ mod actions;
 mod config;
 mod ui;
 
-use crate::config::{Command, Path};
+use crate::config::{Command, Path, Profile};
 use crate::ui::App;
 use anyhow::Result;
 use crossterm::terminal::{enable_raw_mode, EnterAlternateScreen};
@@ -24,13 +24,19 @@ const POLL_DURATION: Duration = Duration::from_millis(100);
 
 lazy_static! {
     static ref path_config: Path = Path::new();
-    static ref ncm_client: Arc<Mutex<NcmClient>> = Arc::new(Mutex::new(NcmClient::new(
-        path_config.api_program.clone(),
-        path_config.login_cookie.clone(),
-        path_config.lyrics.clone(),
-        path_config.settings.clone(),
-    )));
-    static ref player: Arc<Mutex<Player>> = Arc::new(Mutex::new(Player::new()));
+    static ref ncm_client: Arc<Mutex<NcmClient>> = {
+        // 使用上次退出时的账号配置
+        let current_profile = Profile::current(&path_config);
+
+        Arc::new(Mutex::new(NcmClient::new(
+            path_config.api_program.clone(),
+            current_profile.login_cookie,
+            current_profile.lyrics,
+            path_config.settings.clone(),
+            current_profile.settings_override,
+        )))
+    };
+    static ref player: Arc<Mutex<Player>> = Arc::new(Mutex::new(Player::new(Profile::current(&path_config).program_positions)));
     static ref command_queue: Arc<Mutex<VecDeque<Command>>> = Arc::new(Mutex::new(VecDeque::new()));
 }
 
@@ -61,8 +67,17 @@ async fn main() -> Result<()> {
     });
 
     loop {
-        // 检查播放情况
-        player.lock().await.auto_play(ncm_client.lock().await).await?;
+        // 检查播放情况，出错时（网络异常等）在命令行提示，不中断程序
+        let auto_play_result = player.lock().await.auto_play(ncm_client.lock().await).await;
+        if let Err(e) = auto_play_result {
+            app.lock().await.show_error(&e);
+        }
+
+        // 提示因不可获取而跳过的歌曲
+        let skipped_songs = player.lock().await.take_skipped_songs();
+        if !skipped_songs.is_empty() {
+            app.lock().await.show_skipped_songs(&skipped_songs);
+        }
 
         // 根据 Controller 流程，先执行 update_model()，再执行 handle_event()
         app.lock().await.update_model().await?;
@@ -72,6 +87,7 @@ async fn main() -> Result<()> {
         }
 
         if !app.lock().await.handle_event().await? {
+            player.lock().await.store_program_positions();
             ncm_client.lock().await.exit_client().await?;
             return app.lock().await.restore_terminal();
         }
@@ -26,3 +26,14 @@ trait Controller {
     /// 渲染到屏幕
     fn draw(&self, frame: &mut Frame, chunk: Rect);
 }
+
+/// 将时长（ms）格式化为 `mm:ss` ，with_hours 时为 `h:mm:ss`
+fn format_duration(msec: u64, with_hours: bool) -> String {
+    let seconds = msec / 1000;
+
+    if with_hours {
+        format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    } else {
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
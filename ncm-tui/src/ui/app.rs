@@ -2,8 +2,8 @@ use crate::config::LOGO_LINES;
 use crate::ui::widget::{BottomBar, CommandLine};
 use crate::{
     actions, command_queue,
-    config::{AppMode, Command, ScreenEnum},
-    ncm_client, player,
+    config::{AppMode, Command, Profile, ScreenEnum},
+    ncm_client, path_config, player,
     ui::{screen::*, Controller},
 };
 use anyhow::Result;
@@ -14,7 +14,10 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, LeaveAlternateScreen},
 };
-use log::debug;
+use log::{debug, error};
+use ncm_api::model::{Song, Songlist};
+use ncm_api::NcmError;
+use ncm_play::PlayMode;
 use ratatui::prelude::*;
 use ratatui::style::palette::tailwind;
 use ratatui::widgets::Paragraph;
@@ -29,6 +32,15 @@ pub struct App<'a> {
     // view
     main_screen: MainScreen<'a>,
     songlists_screen: SonglistsScreen<'a>,
+    search_screen: SearchScreen<'a>,
+    recommend_screen: RecommendScreen<'a>,
+    toplists_screen: ToplistsScreen<'a>,
+    podcast_screen: PodcastScreen<'a>,
+    history_screen: HistoryScreen<'a>,
+    profile_screen: ProfileScreen<'a>,
+    album_screen: AlbumScreen<'a>,
+    artist_screen: ArtistScreen<'a>,
+    comments_screen: CommentsScreen<'a>,
     login_screen: LoginScreen<'a>,
     help_screen: HelpScreen<'a>,
     command_line: CommandLine<'a>,
@@ -50,6 +62,15 @@ impl<'a> App<'a> {
             need_re_update_view: true,
             main_screen: MainScreen::new(&normal_style),
             songlists_screen: SonglistsScreen::new(&normal_style),
+            search_screen: SearchScreen::new(&normal_style),
+            recommend_screen: RecommendScreen::new(&normal_style),
+            toplists_screen: ToplistsScreen::new(&normal_style),
+            podcast_screen: PodcastScreen::new(&normal_style),
+            history_screen: HistoryScreen::new(&normal_style),
+            profile_screen: ProfileScreen::new(&normal_style),
+            album_screen: AlbumScreen::new(&normal_style),
+            artist_screen: ArtistScreen::new(&normal_style),
+            comments_screen: CommentsScreen::new(&normal_style),
             login_screen: LoginScreen::new(&normal_style),
             help_screen: HelpScreen::new(&normal_style),
             command_line: CommandLine::new(),
@@ -122,6 +143,11 @@ impl<'a> App<'a> {
         // 初始化，获取用户所有歌单（缩略）和 `用户喜欢的音乐` 歌单（详细信息）
         actions::init_songlists().await?;
 
+        // 获取用户喜欢的歌曲，用于显示喜欢状态（失败时不影响使用）
+        if let Err(e) = actions::init_liked_songs().await {
+            error!("failed to load liked songs: {:?}", e);
+        }
+
         // 提醒 main_screen 更新 playlist
         command_queue.lock().await.push_back(Command::RefreshPlaylist);
 
@@ -137,6 +163,36 @@ impl<'a> App<'a> {
         self.command_line.set_content("按下`:`进行命令输入，输入`login`命令进入登录页面");
     }
 
+    /// 在命令行显示错误，api 错误转换为对应的提示
+    pub fn show_error(&mut self, e: &anyhow::Error) {
+        error!("{:?}", e);
+
+        let msg = match e.downcast_ref::<NcmError>() {
+            Some(NcmError::LoginRequired) => String::from("请先登录，输入`login`命令进入登录页面"),
+            Some(NcmError::RateLimited) => String::from("请求过于频繁，请稍后再试"),
+            Some(NcmError::Network(reason)) => format!("网络错误: {}", reason),
+            Some(NcmError::ApiCode { code, message }) => format!("请求失败({}): {}", code, message),
+            Some(NcmError::MalformedResponse(_)) => String::from("无法解析服务器返回的数据"),
+            Some(NcmError::Io(reason)) => format!("文件读写失败: {}", reason),
+            None => e.to_string(),
+        };
+
+        self.command_line.set_content(msg.as_str());
+    }
+
+    /// 在命令行提示因不可获取而跳过的歌曲
+    pub fn show_skipped_songs(&mut self, skipped_songs: &[Song]) {
+        let songs: Vec<String> = skipped_songs
+            .iter()
+            .map(|song| {
+                let reason = if song.availability.is_playable() { "无法获取" } else { song.availability.label() };
+                format!("`{}`（{}）", song.name, reason)
+            })
+            .collect();
+
+        self.command_line.set_content(format!("已跳过不可播放的歌曲：{}", songs.join("、")).as_str());
+    }
+
     pub fn restore_terminal(&mut self) -> Result<()> {
         disable_raw_mode()?;
         execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -155,6 +211,15 @@ impl<'a> App<'a> {
             ScreenEnum::Login => self.update_login_model().await?,
             ScreenEnum::Main => self.main_screen.update_model().await?,
             ScreenEnum::Songlists => self.songlists_screen.update_model().await?,
+            ScreenEnum::Search => self.search_screen.update_model().await?,
+            ScreenEnum::Recommend => self.recommend_screen.update_model().await?,
+            ScreenEnum::Toplists => self.toplists_screen.update_model().await?,
+            ScreenEnum::Podcast => self.podcast_screen.update_model().await?,
+            ScreenEnum::History => self.history_screen.update_model().await?,
+            ScreenEnum::Profile => self.profile_screen.update_model().await?,
+            ScreenEnum::Album => self.album_screen.update_model().await?,
+            ScreenEnum::Artist => self.artist_screen.update_model().await?,
+            ScreenEnum::Comments => self.comments_screen.update_model().await?,
             _ => false,
         };
 
@@ -243,10 +308,38 @@ impl<'a> App<'a> {
                 Command::EnterCommand => {
                     self.switch_to_command_line_mode();
                 },
-                Command::Logout => {
-                    self.login_screen = LoginScreen::new(&self.normal_style);
-                    // TODO: 清除 cache
-                    ncm_client.lock().await.logout().await?;
+                Command::Logout(purge_cache) => {
+                    if !ncm_client.lock().await.is_login() {
+                        self.command_line.set_content("当前未登录");
+                    } else if let Err(e) = actions::logout(purge_cache).await {
+                        self.show_error(&e);
+                    } else {
+                        self.reset_account_screens();
+
+                        // 回到未登录状态的 main_screen
+                        self.init_after_no_login().await;
+                        self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Main)).await?;
+                        self.command_line.set_content("已登出，输入`login`命令重新登录");
+                    }
+                },
+                Command::ListProfiles => {
+                    let current_profile_name = Profile::current(&path_config).name;
+                    let profile_names: Vec<String> = Profile::list(&path_config)
+                        .into_iter()
+                        .map(|name| if name == current_profile_name { format!("*{}", name) } else { name })
+                        .collect();
+                    self.command_line.set_content(format!("账号配置: {}", profile_names.join(" ")).as_str());
+                },
+                Command::SwitchProfile(name) => {
+                    self.switch_profile(&name).await?;
+                },
+                Command::AddProfile(name) => match Profile::create(&path_config, &name) {
+                    Ok(_) => self.switch_profile(&name).await?,
+                    Err(e) => self.show_error(&e),
+                },
+                Command::RemoveProfile(name) => match Profile::remove(&path_config, &name) {
+                    Ok(_) => self.command_line.set_content(format!("已删除账号配置`{}`", name).as_str()),
+                    Err(e) => self.show_error(&e),
                 },
                 Command::PlayOrPause => {
                     player.lock().await.play_or_pause();
@@ -255,18 +348,48 @@ impl<'a> App<'a> {
                     player.lock().await.set_volume(vol);
                 },
                 Command::SwitchPlayMode(play_mode) => {
+                    // 进入私人 FM 后立即开始播放
+                    let is_personal_fm = play_mode == PlayMode::PersonalFm;
                     player.lock().await.set_play_mode(play_mode);
+                    if is_personal_fm {
+                        let mut command_queue_guard = command_queue.lock().await;
+                        command_queue_guard.push_back(Command::StartPlay);
+                        command_queue_guard.push_back(Command::RefreshPlaylist);
+                        drop(command_queue_guard);
+                    }
+                },
+                Command::ShowQuality => {
+                    self.command_line.set_content(actions::current_quality().await.as_str());
+                },
+                Command::SetQuality(quality, for_current_song) => match actions::set_quality(quality, for_current_song).await {
+                    Ok(msg) => self.command_line.set_content(msg.as_str()),
+                    Err(e) => self.show_error(&e),
                 },
                 Command::StartPlay => {
                     if let Err(e) = player.lock().await.start_play(ncm_client.lock().await).await {
-                        self.command_line.set_content(e.to_string().as_str());
+                        self.show_error(&e);
                     }
                 },
+                Command::TrashSong => match actions::trash_current_song().await {
+                    Ok(msg) => self.command_line.set_content(msg.as_str()),
+                    Err(e) => self.show_error(&e),
+                },
+                Command::HeartMode => match actions::start_heart_mode().await {
+                    Ok(msg) => {
+                        self.command_line.set_content(msg.as_str());
+                        command_queue.lock().await.push_back(Command::RefreshPlaylist);
+                    },
+                    Err(e) => self.show_error(&e),
+                },
                 Command::NextSong => {
-                    player.lock().await.play_next_song_now(ncm_client.lock().await).await?;
+                    if let Err(e) = player.lock().await.play_next_song_now(ncm_client.lock().await).await {
+                        self.show_error(&e);
+                    }
                 },
                 Command::PrevSong => {
-                    player.lock().await.play_prev_song_now(ncm_client.lock().await).await?;
+                    if let Err(e) = player.lock().await.play_prev_song_now(ncm_client.lock().await).await {
+                        self.show_error(&e);
+                    }
                 },
                 Command::SearchForward(search_keywords) => {
                     self.switch_to_search_mode(search_keywords);
@@ -274,6 +397,84 @@ impl<'a> App<'a> {
                 Command::SearchBackward(search_keywords) => {
                     self.switch_to_search_mode(search_keywords);
                 },
+                Command::Like | Command::Unlike | Command::ToggleLike => {
+                    let like = match cmd {
+                        Command::Like => Some(true),
+                        Command::Unlike => Some(false),
+                        _ => None,
+                    };
+                    match actions::like_current_song(like).await {
+                        Ok(msg) => self.command_line.set_content(msg.as_str()),
+                        Err(e) => self.show_error(&e),
+                    }
+                },
+                Command::CreateSonglist(name) => match actions::create_songlist(&name).await {
+                    Ok(msg) => self.command_line.set_content(msg.as_str()),
+                    Err(e) => self.show_error(&e),
+                },
+                Command::RenameSonglist(_) | Command::DeleteSonglist => match self.selected_songlist().await {
+                    Some(songlist) => {
+                        let result = match &cmd {
+                            Command::RenameSonglist(name) => actions::rename_songlist(&songlist, name).await,
+                            _ => actions::delete_songlist(&songlist).await,
+                        };
+                        match result {
+                            Ok(msg) => self.command_line.set_content(msg.as_str()),
+                            Err(e) => self.show_error(&e),
+                        }
+                    },
+                    None => self.command_line.set_content("请先在歌单页面选择歌单"),
+                },
+                Command::Subscribe | Command::Unsubscribe => match self.selected_songlist().await {
+                    Some(songlist) => match actions::subscribe_songlist(&songlist, matches!(cmd, Command::Subscribe)).await {
+                        Ok(msg) => self.command_line.set_content(msg.as_str()),
+                        Err(e) => self.show_error(&e),
+                    },
+                    None => self.command_line.set_content("请先选择歌单"),
+                },
+                Command::AddToSonglist(name) => match self.selected_song() {
+                    Some(song) => match actions::add_song_to_songlist(&song, &name).await {
+                        Ok(msg) => self.command_line.set_content(msg.as_str()),
+                        Err(e) => self.show_error(&e),
+                    },
+                    None => self.command_line.set_content("请先选择歌曲"),
+                },
+                Command::RemoveFromSonglist => match self.selected_song_in_songlist().await {
+                    Some((songlist, song)) => match actions::remove_song_from_songlist(&song, &songlist).await {
+                        Ok(msg) => {
+                            self.command_line.set_content(msg.as_str());
+                            if self.current_screen == ScreenEnum::Songlists {
+                                self.songlists_screen.reload_current_songlist().await;
+                            }
+                        },
+                        Err(e) => self.show_error(&e),
+                    },
+                    None => self.command_line.set_content("请先在播放列表或歌单页面中选择歌单内的歌曲"),
+                },
+                Command::GlobalSearch(_) => {
+                    // 切换到 search_screen ，搜索由 search_screen 完成
+                    self.switch_screen(ScreenEnum::Search).await;
+                    self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Search)).await?;
+                },
+                Command::OpenAlbum(_) => {
+                    // 切换到 album_screen ，专辑加载由 album_screen 完成
+                    self.switch_screen(ScreenEnum::Album).await;
+                    self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Album)).await?;
+                },
+                Command::OpenArtist(_) => {
+                    // 切换到 artist_screen ，歌手信息加载由 artist_screen 完成
+                    self.switch_screen(ScreenEnum::Artist).await;
+                    self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Artist)).await?;
+                },
+                Command::ShowComments => match self.selected_song().or(player.lock().await.current_song().clone()) {
+                    Some(song) => command_queue.lock().await.push_back(Command::OpenComments(Box::new(song))),
+                    None => self.command_line.set_content("请先选择歌曲"),
+                },
+                Command::OpenComments(_) => {
+                    // 切换到 comments_screen ，评论加载由 comments_screen 完成
+                    self.switch_screen(ScreenEnum::Comments).await;
+                    self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Comments)).await?;
+                },
                 _ => {},
             }
 
@@ -292,17 +493,45 @@ impl<'a> App<'a> {
                     | Command::GoToBottom
                     | Command::SearchForward(_)
                     | Command::SearchBackward(_)
+                    | Command::GlobalSearch(_)
+                    | Command::ShowAlbum
+                    | Command::OpenAlbum(_)
+                    | Command::ShowArtist(_)
+                    | Command::OpenArtist(_)
+                    | Command::OpenComments(_)
+                    | Command::ToggleLikeComment
+                    | Command::Enqueue
+                    | Command::MoveSongUp
+                    | Command::MoveSongDown
+                    | Command::MoveSongTo(_)
                     | Command::RefreshPlaylist
             ) {
                 // 先 update_model(), 再 handle_event()
                 // 取或值
                 // 若写成 self.need_re_update_view = self.need_re_update_view || match ... {} ，match块内的方法可能不被执行
-                self.need_re_update_view = match self.current_screen {
-                    ScreenEnum::Main => self.main_screen.handle_event(cmd).await?,
-                    ScreenEnum::Songlists => self.songlists_screen.handle_event(cmd).await?,
-                    ScreenEnum::Login => self.login_screen.handle_event(cmd).await?,
-                    ScreenEnum::Help => self.help_screen.handle_event(cmd).await?,
-                    _ => false,
+                let screen_handle_result = match self.current_screen {
+                    ScreenEnum::Main => self.main_screen.handle_event(cmd).await,
+                    ScreenEnum::Songlists => self.songlists_screen.handle_event(cmd).await,
+                    ScreenEnum::Search => self.search_screen.handle_event(cmd).await,
+                    ScreenEnum::Recommend => self.recommend_screen.handle_event(cmd).await,
+                    ScreenEnum::Toplists => self.toplists_screen.handle_event(cmd).await,
+                    ScreenEnum::Podcast => self.podcast_screen.handle_event(cmd).await,
+                    ScreenEnum::History => self.history_screen.handle_event(cmd).await,
+                    ScreenEnum::Profile => self.profile_screen.handle_event(cmd).await,
+                    ScreenEnum::Album => self.album_screen.handle_event(cmd).await,
+                    ScreenEnum::Artist => self.artist_screen.handle_event(cmd).await,
+                    ScreenEnum::Comments => self.comments_screen.handle_event(cmd).await,
+                    ScreenEnum::Login => self.login_screen.handle_event(cmd).await,
+                    ScreenEnum::Help => self.help_screen.handle_event(cmd).await,
+                    _ => Ok(false),
+                };
+                // 出错时（网络异常、未登录等）在命令行提示，不中断程序
+                self.need_re_update_view = match screen_handle_result {
+                    Ok(need_re_update_view) => need_re_update_view,
+                    Err(e) => {
+                        self.show_error(&e);
+                        true
+                    },
                 } || self.need_re_update_view;
             }
         }
@@ -318,6 +547,15 @@ impl<'a> App<'a> {
                 ScreenEnum::Login => self.login_screen.update_view(&self.normal_style),
                 ScreenEnum::Main => self.main_screen.update_view(&self.normal_style),
                 ScreenEnum::Songlists => self.songlists_screen.update_view(&self.normal_style),
+                ScreenEnum::Search => self.search_screen.update_view(&self.normal_style),
+                ScreenEnum::Recommend => self.recommend_screen.update_view(&self.normal_style),
+                ScreenEnum::Toplists => self.toplists_screen.update_view(&self.normal_style),
+                ScreenEnum::Podcast => self.podcast_screen.update_view(&self.normal_style),
+                ScreenEnum::History => self.history_screen.update_view(&self.normal_style),
+                ScreenEnum::Profile => self.profile_screen.update_view(&self.normal_style),
+                ScreenEnum::Album => self.album_screen.update_view(&self.normal_style),
+                ScreenEnum::Artist => self.artist_screen.update_view(&self.normal_style),
+                ScreenEnum::Comments => self.comments_screen.update_view(&self.normal_style),
                 _ => {},
             }
         }
@@ -351,6 +589,15 @@ impl<'a> App<'a> {
                 ScreenEnum::Login => self.login_screen.draw(frame, chunks[0]),
                 ScreenEnum::Main => self.main_screen.draw(frame, chunks[0]),
                 ScreenEnum::Songlists => self.songlists_screen.draw(frame, chunks[0]),
+                ScreenEnum::Search => self.search_screen.draw(frame, chunks[0]),
+                ScreenEnum::Recommend => self.recommend_screen.draw(frame, chunks[0]),
+                ScreenEnum::Toplists => self.toplists_screen.draw(frame, chunks[0]),
+                ScreenEnum::Podcast => self.podcast_screen.draw(frame, chunks[0]),
+                ScreenEnum::History => self.history_screen.draw(frame, chunks[0]),
+                ScreenEnum::Profile => self.profile_screen.draw(frame, chunks[0]),
+                ScreenEnum::Album => self.album_screen.draw(frame, chunks[0]),
+                ScreenEnum::Artist => self.artist_screen.draw(frame, chunks[0]),
+                ScreenEnum::Comments => self.comments_screen.draw(frame, chunks[0]),
                 _ => {},
             }
 
@@ -373,6 +620,8 @@ impl<'a> App<'a> {
             KeyCode::Char('j') => Command::Down,
             KeyCode::Up => Command::Up,
             KeyCode::Char('k') => Command::Up,
+            KeyCode::Char('J') => Command::MoveSongDown,
+            KeyCode::Char('K') => Command::MoveSongUp,
             KeyCode::Char(' ') => Command::PlayOrPause,
             KeyCode::Enter => {
                 if key_modifiers.contains(KeyModifiers::ALT) {
@@ -388,11 +637,23 @@ impl<'a> App<'a> {
             KeyCode::Char('h') => Command::PrevPanel,
             KeyCode::Char('1') => Command::GotoScreen(ScreenEnum::Main),
             KeyCode::Char('2') => Command::GotoScreen(ScreenEnum::Songlists),
+            KeyCode::Char('3') => Command::GotoScreen(ScreenEnum::Search),
+            KeyCode::Char('4') => Command::GotoScreen(ScreenEnum::Recommend),
+            KeyCode::Char('5') => Command::GotoScreen(ScreenEnum::Toplists),
+            KeyCode::Char('6') => Command::GotoScreen(ScreenEnum::Podcast),
+            KeyCode::Char('7') => Command::GotoScreen(ScreenEnum::History),
+            KeyCode::Char('8') => Command::GotoScreen(ScreenEnum::Profile),
             KeyCode::Char('0') => Command::GotoScreen(ScreenEnum::Help),
             KeyCode::F(1) => Command::GotoScreen(ScreenEnum::Help),
             KeyCode::Char('.') | KeyCode::Char('。') => Command::NextSong,
             KeyCode::Char(',') | KeyCode::Char('，') => Command::PrevSong,
             KeyCode::Char(':') | KeyCode::Char('：') => Command::EnterCommand,
+            KeyCode::Char('f') => Command::ToggleLike,
+            KeyCode::Char('a') => Command::ShowAlbum,
+            KeyCode::Char('s') => Command::ShowArtist(None),
+            KeyCode::Char('e') => Command::Enqueue,
+            KeyCode::Char('c') => Command::ShowComments,
+            KeyCode::Char('F') => Command::ToggleLikeComment,
             KeyCode::Char('/') => {
                 self.switch_to_search_input_mode();
                 self.command_line.set_content("/ ");
@@ -462,6 +723,81 @@ impl<'a> App<'a> {
         }
     }
 
+    /// 当前页面中所选的歌曲
+    fn selected_song(&self) -> Option<Song> {
+        match self.current_screen {
+            ScreenEnum::Main => self.main_screen.selected_song(),
+            ScreenEnum::Songlists => self.songlists_screen.selected_song().map(|(_, song)| song),
+            ScreenEnum::Search => self.search_screen.selected_song(),
+            ScreenEnum::Recommend => self.recommend_screen.selected_song(),
+            ScreenEnum::Toplists => self.toplists_screen.selected_song(),
+            ScreenEnum::Podcast => self.podcast_screen.selected_song(),
+            ScreenEnum::History => self.history_screen.selected_song(),
+            ScreenEnum::Profile => self.profile_screen.selected_song(),
+            ScreenEnum::Album => self.album_screen.selected_song(),
+            ScreenEnum::Artist => self.artist_screen.selected_song(),
+            ScreenEnum::Comments => self.comments_screen.selected_song(),
+            _ => None,
+        }
+    }
+
+    /// 歌单操作的对象：歌单、搜索、推荐和排行榜页面中为所选的歌单，其他页面中为当前播放的歌单
+    async fn selected_songlist(&self) -> Option<Songlist> {
+        match self.current_screen {
+            ScreenEnum::Songlists => self.songlists_screen.selected_songlist(),
+            ScreenEnum::Search => self.search_screen.selected_songlist(),
+            ScreenEnum::Recommend => self.recommend_screen.selected_songlist(),
+            ScreenEnum::Toplists => self.toplists_screen.selected_songlist(),
+            // 电台、最近播放和听歌排行不是真实的歌单
+            ScreenEnum::Podcast | ScreenEnum::History | ScreenEnum::Profile => None,
+            _ => actions::current_songlist().await,
+        }
+    }
+
+    /// 所选的歌曲及其所在的歌单：歌单页面中为歌单内容中所选的歌曲，main_screen 中为当前播放的歌单中所选的歌曲
+    async fn selected_song_in_songlist(&self) -> Option<(Songlist, Song)> {
+        match self.current_screen {
+            ScreenEnum::Songlists => self.songlists_screen.selected_song(),
+            ScreenEnum::Main => Some((actions::current_songlist().await?, self.main_screen.selected_song()?)),
+            _ => None,
+        }
+    }
+
+    /// 切换账号配置，无需重启即可使用另一个账号
+    async fn switch_profile(&mut self, name: &str) -> Result<()> {
+        match actions::switch_profile(name).await {
+            Ok(is_login) => {
+                self.reset_account_screens();
+
+                if is_login {
+                    self.init_after_login().await?;
+                    self.command_line.set_content(format!("已切换到账号配置`{}`", name).as_str());
+                } else {
+                    self.init_after_no_login().await;
+                    self.command_line.set_content(format!("已切换到账号配置`{}`，输入`login`命令登录", name).as_str());
+                }
+                self.command_line.handle_event(Command::GotoScreen(ScreenEnum::Main)).await?;
+            },
+            Err(e) => self.show_error(&e),
+        }
+
+        Ok(())
+    }
+
+    /// 释放与账号相关的页面（登出或切换账号配置时调用）
+    fn reset_account_screens(&mut self) {
+        self.login_screen = LoginScreen::new(&self.normal_style);
+        self.songlists_screen = SonglistsScreen::new(&self.normal_style);
+        self.search_screen = SearchScreen::new(&self.normal_style);
+        self.recommend_screen = RecommendScreen::new(&self.normal_style);
+        self.podcast_screen = PodcastScreen::new(&self.normal_style);
+        self.history_screen = HistoryScreen::new(&self.normal_style);
+        self.profile_screen = ProfileScreen::new(&self.normal_style);
+        self.album_screen = AlbumScreen::new(&self.normal_style);
+        self.artist_screen = ArtistScreen::new(&self.normal_style);
+        self.comments_screen = CommentsScreen::new(&self.normal_style);
+    }
+
     async fn switch_screen(&mut self, to_screen: ScreenEnum) {
         // 已登录状态不能切换到 login_screen
         let ncm_client_guard = ncm_client.lock().await;
@@ -477,9 +813,10 @@ impl<'a> App<'a> {
         }
         drop(ncm_client_guard);
 
-        // 切换到 main_screen 时显示提示
+        // 切换到 main_screen 时显示提示，并刷新播放列表（其他页面可能有歌曲加入了待播队列）
         if to_screen == ScreenEnum::Main {
             self.command_line.set_content("按0或F1键查看help页面");
+            command_queue.lock().await.push_back(Command::RefreshPlaylist);
         }
 
         // 切换到 main_screen 时释放当前屏幕（节省内存开销）
@@ -490,6 +827,21 @@ impl<'a> App<'a> {
             ScreenEnum::Songlists => {
                 self.songlists_screen = SonglistsScreen::new(&self.normal_style);
             },
+            ScreenEnum::Recommend => {
+                self.recommend_screen = RecommendScreen::new(&self.normal_style);
+            },
+            ScreenEnum::Toplists => {
+                self.toplists_screen = ToplistsScreen::new(&self.normal_style);
+            },
+            ScreenEnum::Podcast => {
+                self.podcast_screen = PodcastScreen::new(&self.normal_style);
+            },
+            ScreenEnum::History => {
+                self.history_screen = HistoryScreen::new(&self.normal_style);
+            },
+            ScreenEnum::Profile => {
+                self.profile_screen = ProfileScreen::new(&self.normal_style);
+            },
             _ => {},
         }
 
@@ -1,7 +1,13 @@
+mod albums_panel;
+mod artists_panel;
+mod comments_panel;
 mod lyric_panel;
 mod playlist_panel;
 mod songlist_candidates_panel;
 
+pub use albums_panel::*;
+pub use artists_panel::*;
+pub use comments_panel::*;
 pub use lyric_panel::*;
 pub use playlist_panel::*;
 pub use songlist_candidates_panel::*;
@@ -0,0 +1,158 @@
+use crate::config::style::*;
+use crate::config::Command;
+use crate::ui::panel::PanelFocusedStatus;
+use crate::ui::Controller;
+use ncm_api::model::Album;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Constraint, Style};
+use ratatui::style::palette::tailwind;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState};
+use ratatui::Frame;
+
+pub struct AlbumsPanel<'a> {
+    // model
+    pub focused_status: PanelFocusedStatus, // 聚焦状态交给父 screen 管理，面板自身只读不写
+    //
+    title: String,
+    albums: Vec<Album>,
+    albums_table_rows: Vec<Row<'a>>,
+    albums_table_state: TableState,
+    scrollbar_state: ScrollbarState,
+
+    // view
+    albums_table: Table<'a>,
+}
+
+impl<'a> AlbumsPanel<'a> {
+    pub fn new(focused_status: PanelFocusedStatus) -> Self {
+        Self {
+            focused_status,
+            title: String::new(),
+            albums: Vec::new(),
+            albums_table_rows: Vec::new(),
+            albums_table_state: TableState::new(),
+            scrollbar_state: ScrollbarState::new(0),
+            albums_table: Table::default(),
+        }
+    }
+}
+
+impl<'a> AlbumsPanel<'a> {
+    /// 手动设置 model
+    pub fn set_model(&mut self, title: &str, albums: &[Album]) {
+        self.title = title.to_string();
+        self.albums = albums.to_vec();
+        self.albums_table_rows = albums
+            .iter()
+            .map(|album| Row::from_iter(vec![Cell::new(album.name.clone()), Cell::new(album.artist.clone()), Cell::new(format!("{:>6}", album.songs_count))]))
+            .collect();
+
+        // 防止悬空
+        self.albums_table_state.select(None);
+
+        self.scrollbar_state = ScrollbarState::new(self.albums_table_rows.len());
+    }
+
+    /// 选中指定行（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.albums_table_rows.len() {
+            self.albums_table_state.select(Some(index));
+            self.scrollbar_state = self.scrollbar_state.position(index);
+        }
+    }
+
+    pub fn get_selected_album(&self) -> Option<Album> {
+        if let Some(selected) = self.albums_table_state.selected() {
+            if let Some(album) = self.albums.get(selected) {
+                return Some(album.clone());
+            }
+        }
+
+        None
+    }
+
+    pub fn get_selected_index(&self) -> Option<usize> {
+        self.albums_table_state.selected()
+    }
+}
+
+impl<'a> Controller for AlbumsPanel<'a> {
+    async fn update_model(&mut self) -> anyhow::Result<bool> {
+        let mut result = Ok(false);
+
+        if self.albums_table_state.selected().is_none() && !self.albums_table_rows.is_empty() {
+            self.albums_table_state.select(Some(0));
+            self.scrollbar_state.first();
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
+        match cmd {
+            Command::Down => {
+                // 直接使用 select_next() 存在越界问题
+                if let (Some(selected), list_len) = (self.albums_table_state.selected(), self.albums_table_rows.len()) {
+                    if selected + 1 < list_len {
+                        self.albums_table_state.select_next();
+                        self.scrollbar_state.next();
+                    }
+                }
+            },
+            Command::Up => {
+                self.albums_table_state.select_previous();
+                self.scrollbar_state.prev();
+            },
+            Command::GoToTop => {
+                self.albums_table_state.select_first();
+                self.scrollbar_state.first();
+            },
+            Command::GoToBottom if !self.albums_table_rows.is_empty() => {
+                // 使用 select_last() 会越界
+                self.albums_table_state.select(Some(self.albums_table_rows.len() - 1));
+                self.scrollbar_state.last();
+            },
+            _ => {},
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, _style: &Style) {
+        let mut albums_table = Table::new(self.albums_table_rows.clone(), [Constraint::Min(30), Constraint::Min(10), Constraint::Max(6)])
+            .header(Row::new(vec![Cell::new("专辑"), Cell::new("歌手"), Cell::new("歌曲数")]).style(TABLE_HEADER_STYLE).height(1))
+            .block({
+                let mut block = Block::default().title(Line::from(self.title.clone())).borders(Borders::ALL);
+                if self.focused_status == PanelFocusedStatus::Outside {
+                    block = block.border_style(PANEL_SELECTED_BORDER_STYLE);
+                }
+
+                block
+            });
+
+        // highlight
+        if self.focused_status == PanelFocusedStatus::Inside {
+            albums_table = albums_table.row_highlight_style(ITEM_SELECTED_STYLE).highlight_symbol(">")
+        }
+
+        self.albums_table = albums_table;
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        let mut albums_table_state = self.albums_table_state.clone();
+        frame.render_stateful_widget(&self.albums_table, chunk, &mut albums_table_state);
+
+        // 渲染 scrollbar
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .thumb_style(tailwind::ROSE.c800);
+        let scrollbar_area = chunk.inner(Margin { vertical: 1, horizontal: 0 });
+        let mut scrollbar_state = self.scrollbar_state;
+        frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+    }
+}
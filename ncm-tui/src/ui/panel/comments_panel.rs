@@ -0,0 +1,177 @@
+use crate::config::style::*;
+use crate::config::Command;
+use crate::ui::panel::PanelFocusedStatus;
+use crate::ui::Controller;
+use ncm_api::model::Comment;
+use ratatui::layout::{Margin, Rect};
+use ratatui::prelude::{Constraint, Style};
+use ratatui::style::palette::tailwind;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState};
+use ratatui::Frame;
+
+pub struct CommentsPanel<'a> {
+    // model
+    pub focused_status: PanelFocusedStatus, // 聚焦状态交给父 screen 管理，面板自身只读不写
+    //
+    title: String,
+    comments: Vec<Comment>,
+    comments_table_rows: Vec<Row<'a>>,
+    comments_table_state: TableState,
+    scrollbar_state: ScrollbarState,
+
+    // view
+    comments_table: Table<'a>,
+}
+
+impl<'a> CommentsPanel<'a> {
+    pub fn new(focused_status: PanelFocusedStatus) -> Self {
+        Self {
+            focused_status,
+            title: String::new(),
+            comments: Vec::new(),
+            comments_table_rows: Vec::new(),
+            comments_table_state: TableState::new(),
+            scrollbar_state: ScrollbarState::new(0),
+            comments_table: Table::default(),
+        }
+    }
+}
+
+impl<'a> CommentsPanel<'a> {
+    /// 手动设置 model
+    pub fn set_model(&mut self, title: &str, comments: &[Comment]) {
+        self.title = title.to_string();
+        self.comments = comments.to_vec();
+        self.comments_table_rows = comments.iter().map(comment_row).collect();
+
+        // 防止悬空
+        self.comments_table_state.select(None);
+
+        self.scrollbar_state = ScrollbarState::new(self.comments_table_rows.len());
+    }
+
+    /// 更新指定评论（如点赞后），不改变选中项
+    pub fn set_comment(&mut self, index: usize, comment: &Comment) {
+        if index < self.comments.len() {
+            self.comments[index] = comment.clone();
+            self.comments_table_rows[index] = comment_row(comment);
+        }
+    }
+
+    /// 选中指定行（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.comments_table_rows.len() {
+            self.comments_table_state.select(Some(index));
+            self.scrollbar_state = self.scrollbar_state.position(index);
+        }
+    }
+
+    pub fn get_selected_comment(&self) -> Option<Comment> {
+        if let Some(selected) = self.comments_table_state.selected() {
+            if let Some(comment) = self.comments.get(selected) {
+                return Some(comment.clone());
+            }
+        }
+
+        None
+    }
+
+    pub fn get_selected_index(&self) -> Option<usize> {
+        self.comments_table_state.selected()
+    }
+}
+
+impl<'a> Controller for CommentsPanel<'a> {
+    async fn update_model(&mut self) -> anyhow::Result<bool> {
+        let mut result = Ok(false);
+
+        if self.comments_table_state.selected().is_none() && !self.comments_table_rows.is_empty() {
+            self.comments_table_state.select(Some(0));
+            self.scrollbar_state.first();
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
+        match cmd {
+            Command::Down => {
+                // 直接使用 select_next() 存在越界问题
+                if let (Some(selected), list_len) = (self.comments_table_state.selected(), self.comments_table_rows.len()) {
+                    if selected + 1 < list_len {
+                        self.comments_table_state.select_next();
+                        self.scrollbar_state.next();
+                    }
+                }
+            },
+            Command::Up => {
+                self.comments_table_state.select_previous();
+                self.scrollbar_state.prev();
+            },
+            Command::GoToTop => {
+                self.comments_table_state.select_first();
+                self.scrollbar_state.first();
+            },
+            Command::GoToBottom if !self.comments_table_rows.is_empty() => {
+                // 使用 select_last() 会越界
+                self.comments_table_state.select(Some(self.comments_table_rows.len() - 1));
+                self.scrollbar_state.last();
+            },
+            _ => {},
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, _style: &Style) {
+        let mut comments_table = Table::new(self.comments_table_rows.clone(), [Constraint::Max(16), Constraint::Min(20), Constraint::Max(10), Constraint::Max(16)])
+            .header(
+                Row::new(vec![Cell::new("用户"), Cell::new("评论"), Cell::new("赞"), Cell::new("时间")])
+                    .style(TABLE_HEADER_STYLE)
+                    .height(1),
+            )
+            .block({
+                let mut block = Block::default().title(Line::from(self.title.clone())).borders(Borders::ALL);
+                if self.focused_status == PanelFocusedStatus::Outside {
+                    block = block.border_style(PANEL_SELECTED_BORDER_STYLE);
+                }
+
+                block
+            });
+
+        // highlight
+        if self.focused_status == PanelFocusedStatus::Inside {
+            comments_table = comments_table.row_highlight_style(ITEM_SELECTED_STYLE).highlight_symbol(">")
+        }
+
+        self.comments_table = comments_table;
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        let mut comments_table_state = self.comments_table_state.clone();
+        frame.render_stateful_widget(&self.comments_table, chunk, &mut comments_table_state);
+
+        // 渲染 scrollbar
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .track_symbol(None)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .thumb_style(tailwind::ROSE.c800);
+        let scrollbar_area = chunk.inner(Margin { vertical: 1, horizontal: 0 });
+        let mut scrollbar_state = self.scrollbar_state;
+        frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+    }
+}
+
+/// 评论只显示为一行，完整内容由父 screen 显示
+fn comment_row<'a>(comment: &Comment) -> Row<'a> {
+    Row::from_iter(vec![
+        Cell::new(comment.user_name.clone()),
+        Cell::new(comment.content.replace('\n', " ")),
+        Cell::new(format!("{}{:>8}", if comment.liked { '\u{2665}' } else { ' ' }, comment.liked_count)),
+        Cell::new(comment.time_str()),
+    ])
+}
@@ -133,8 +133,8 @@ impl<'a> Controller for LyricPanel<'a> {
         song_lyric_list = match self.song.clone() {
             Some(song) => song_lyric_list.block({
                 let mut block = Block::default()
-                    .title(Line::from(format!("\u{1F3B5}{}", song.name)).left_aligned())
-                    .title(Line::from(format!("\u{1F3A4}{}", song.singer)).right_aligned())
+                    .title(Line::from(format!("\u{1F3B5}{}", song.full_name())).left_aligned())
+                    .title(Line::from(format!("\u{1F3A4}{}", song.singer())).right_aligned())
                     .title_bottom(Line::from(format!("\u{1F4DA}{}", song.album)).centered())
                     .borders(Borders::ALL);
                 if self.focused_status == PanelFocusedStatus::Outside {
@@ -1,7 +1,7 @@
 use crate::config::style::*;
 use crate::config::Command;
 use crate::ui::panel::PanelFocusedStatus;
-use crate::ui::Controller;
+use crate::ui::{format_duration, Controller};
 use crate::{ncm_client, player};
 use ncm_api::model::Song;
 use ratatui::layout::{Constraint, Rect};
@@ -9,12 +9,18 @@ use ratatui::prelude::{Margin, Style};
 use ratatui::style::palette::tailwind;
 use ratatui::widgets::{Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState};
 use ratatui::Frame;
+use std::collections::HashSet;
 
 pub struct PlaylistPanel<'a> {
     // model
     pub focused_status: PanelFocusedStatus, // 聚焦状态交给父 screen 管理，面板自身只读不写
     //
     playlist_name: String,
+    playlist: Vec<Song>,
+    current_playlist_revision: usize, // 上次同步的播放器播放列表版本（仅 main_screen 使用）
+    liked_song_ids: HashSet<u64>,     // 用户喜欢的歌曲（ncm_client 缓存的副本）
+    liked_songs_revision: usize,
+    songs_availability_revision: usize,
     playlist_table_rows: Vec<Row<'a>>,
     playlist_table_state: TableState,
     scrollbar_state: ScrollbarState,
@@ -28,6 +34,11 @@ impl<'a> PlaylistPanel<'a> {
         Self {
             focused_status,
             playlist_name: String::new(),
+            playlist: Vec::new(),
+            current_playlist_revision: 0,
+            liked_song_ids: HashSet::new(),
+            liked_songs_revision: 0,
+            songs_availability_revision: 0,
             playlist_table_rows: Vec::new(),
             playlist_table_state: TableState::new(),
             scrollbar_state: ScrollbarState::new(0),
@@ -40,12 +51,11 @@ impl<'a> PlaylistPanel<'a> {
     /// 根据当前播放列表更新 model
     async fn update_model_by_current_playlist(&mut self) -> anyhow::Result<()> {
         let player_guard = player.lock().await;
-        let current_playlist_name = player_guard.current_playlist_name();
 
-        if self.playlist_name != *current_playlist_name {
-            let current_playlist = player_guard.current_playlist();
-
-            self.set_model(current_playlist_name, current_playlist);
+        // 切换了播放列表，或有歌曲被加入播放队列（同名、同长度的新列表也会刷新）
+        if self.current_playlist_revision != player_guard.current_playlist_revision() {
+            self.current_playlist_revision = player_guard.current_playlist_revision();
+            self.set_model(player_guard.current_playlist_name(), player_guard.current_playlist());
         }
 
         Ok(())
@@ -56,22 +66,64 @@ impl<'a> PlaylistPanel<'a> {
     /// 在 main_screen 由 self.update_model_by_current_playlist() 调用，在 playlist_screen 由外部直接调用
     pub fn set_model(&mut self, playlist_name: &String, playlist: &Vec<Song>) {
         self.playlist_name = playlist_name.clone();
-        self.playlist_table_rows = playlist
+        self.playlist = playlist.clone();
+        self.update_playlist_table_rows();
+
+        // 更新 playlist_table 的 selected，防止悬空
+        self.playlist_table_state.select(None);
+
+        self.scrollbar_state = ScrollbarState::new(self.playlist_table_rows.len());
+    }
+
+    /// 根据 playlist 和喜欢的歌曲生成表格行，不可完整播放的歌曲置灰并标注原因
+    fn update_playlist_table_rows(&mut self) {
+        self.playlist_table_rows = self
+            .playlist
             .iter()
             .map(|song| {
+                let (name, style) = if song.availability.is_playable() {
+                    (song.name.clone(), Style::default())
+                } else {
+                    (format!("{} [{}]", song.name, song.availability.label()), ITEM_UNAVAILABLE_STYLE)
+                };
+
                 Row::from_iter(vec![
-                    Cell::new(song.name.clone()),
-                    Cell::new(song.singer.clone()),
+                    Cell::new(if self.liked_song_ids.contains(&song.id) { "\u{2665}" } else { "" }),
+                    Cell::new(name),
+                    Cell::new(song.singer()),
                     Cell::new(song.album.clone()),
-                    Cell::new(format!("{:02}:{:02}", song.duration.clone() / 60000, song.duration.clone() % 60000 / 1000)),
+                    // 时长超过 1 小时（如电台节目）时带上小时
+                    Cell::new(format_duration(song.duration, song.duration >= 3600 * 1000)),
                 ])
+                .style(style)
             })
             .collect();
+    }
 
-        // 更新 playlist_table 的 selected，防止悬空
-        self.playlist_table_state.select(None);
+    pub fn get_selected_index(&self) -> Option<usize> {
+        self.playlist_table_state.selected()
+    }
 
-        self.scrollbar_state = ScrollbarState::new(self.playlist_table_rows.len());
+    pub fn get_selected_song(&self) -> Option<Song> {
+        self.playlist.get(self.playlist_table_state.selected()?).cloned()
+    }
+
+    /// 将 from 处的歌曲移动到 to 处并选中（越界时忽略）
+    pub fn move_song(&mut self, from: usize, to: usize) {
+        if from < self.playlist.len() && to < self.playlist.len() {
+            let song = self.playlist.remove(from);
+            self.playlist.insert(to, song);
+            self.update_playlist_table_rows();
+            self.select(to);
+        }
+    }
+
+    /// 选中指定行（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if index < self.playlist_table_rows.len() {
+            self.playlist_table_state.select(Some(index));
+            self.scrollbar_state = self.scrollbar_state.position(index);
+        }
     }
 }
 
@@ -79,6 +131,31 @@ impl<'a> Controller for PlaylistPanel<'a> {
     async fn update_model(&mut self) -> anyhow::Result<bool> {
         let mut result = Ok(false);
 
+        // 喜欢的歌曲有变化时更新表格（ncm_client 正在请求时跳过，下一轮再检查，避免阻塞界面）
+        if let Ok(ncm_client_guard) = ncm_client.try_lock() {
+            if self.liked_songs_revision != ncm_client_guard.liked_songs_revision() {
+                self.liked_songs_revision = ncm_client_guard.liked_songs_revision();
+                self.liked_song_ids = ncm_client_guard.liked_song_ids().clone();
+                drop(ncm_client_guard);
+
+                self.update_playlist_table_rows();
+                result = Ok(true);
+            }
+        }
+
+        // 歌曲可获取状态有更新时更新表格（不改变所选行）
+        let player_guard = player.lock().await;
+        if self.songs_availability_revision != player_guard.songs_availability_revision() {
+            self.songs_availability_revision = player_guard.songs_availability_revision();
+            for song in self.playlist.iter_mut() {
+                song.availability = player_guard.song_availability(song.id);
+            }
+            drop(player_guard);
+
+            self.update_playlist_table_rows();
+            result = Ok(true);
+        }
+
         if self.playlist_table_state.selected() == None && !self.playlist_table_rows.is_empty() {
             self.playlist_table_state.select(Some(0));
             self.scrollbar_state.first();
@@ -153,9 +230,11 @@ impl<'a> Controller for PlaylistPanel<'a> {
     fn update_view(&mut self, _style: &Style) {
         let header_style = Style::default().fg(tailwind::WHITE).bg(tailwind::RED.c300);
 
-        let mut playlist_table = Table::new(self.playlist_table_rows.clone(), [Constraint::Min(40), Constraint::Min(15), Constraint::Min(15), Constraint::Length(6)])
+        let widths = [Constraint::Length(1), Constraint::Min(40), Constraint::Min(15), Constraint::Min(15), Constraint::Length(7)];
+
+        let mut playlist_table = Table::new(self.playlist_table_rows.clone(), widths)
             .header(
-                Row::new(vec![Cell::new("曲名"), Cell::new("歌手/乐手"), Cell::new("专辑"), Cell::new("时长")])
+                Row::new(vec![Cell::new(""), Cell::new("曲名"), Cell::new("歌手/乐手"), Cell::new("专辑"), Cell::new("时长")])
                     .style(header_style)
                     .height(1),
             )
@@ -7,7 +7,7 @@ use ncm_api::model::Songlist;
 use ratatui::layout::{Margin, Rect};
 use ratatui::prelude::{Constraint, Style};
 use ratatui::style::palette::tailwind;
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState};
 use ratatui::Frame;
 
@@ -15,9 +15,12 @@ pub struct SonglistsPanel<'a> {
     // model
     pub focused_status: PanelFocusedStatus, // 聚焦状态交给父 screen 管理，面板自身只读不写
     //
-    username: String,
+    title: String,
+    is_manual_model: bool, // 手动设置 model 时不再自动装载用户歌单
+    songlists_revision: usize,
     songlists: Vec<Songlist>,
     songlists_table_rows: Vec<Row<'a>>,
+    row_songlist_indices: Vec<Option<usize>>, // 每一行对应的歌单下标，分组标题行为 None
     songlists_table_state: TableState,
     scrollbar_state: ScrollbarState,
 
@@ -29,9 +32,12 @@ impl<'a> SonglistsPanel<'a> {
     pub fn new(focused_status: PanelFocusedStatus) -> Self {
         Self {
             focused_status,
-            username: String::new(),
+            title: String::new(),
+            is_manual_model: false,
+            songlists_revision: 0,
             songlists: Vec::new(),
             songlists_table_rows: Vec::new(),
+            row_songlist_indices: Vec::new(),
             songlists_table_state: TableState::new(),
             scrollbar_state: ScrollbarState::new(0),
             songlists_table: Table::default(),
@@ -40,8 +46,31 @@ impl<'a> SonglistsPanel<'a> {
 }
 
 impl<'a> SonglistsPanel<'a> {
+    /// 手动设置 model
+    ///
+    /// 在 songlists_screen 由 self.update_model() 自动装载用户歌单，在 search_screen 等处由外部直接调用
+    pub fn set_model(&mut self, title: &str, songlists: &[Songlist]) {
+        self.is_manual_model = true;
+        self.title = title.to_string();
+        self.songlists = songlists.to_vec();
+        self.songlists_table_rows = songlists.iter().map(songlist_to_row).collect();
+        self.row_songlist_indices = (0..songlists.len()).map(Some).collect();
+
+        // 防止悬空
+        self.songlists_table_state.select(None);
+
+        self.scrollbar_state = ScrollbarState::new(self.songlists_table_rows.len());
+    }
+
+    /// 选中指定歌单（越界时忽略）
+    pub fn select(&mut self, index: usize) {
+        if let Some(row) = self.row_songlist_indices.iter().position(|songlist_index| *songlist_index == Some(index)) {
+            self.select_row(row);
+        }
+    }
+
     pub fn get_selected_songlist(&self) -> Option<Songlist> {
-        if let Some(selected) = self.songlists_table_state.selected() {
+        if let Some(selected) = self.get_selected_songlist_index() {
             if let Some(songlist) = self.songlists.get(selected) {
                 return Some(songlist.clone());
             }
@@ -50,8 +79,45 @@ impl<'a> SonglistsPanel<'a> {
         None
     }
 
+    /// 选中歌单在 songlists 中的下标（与播放器中的歌单顺序一致，不受分组显示影响）
     pub fn get_selected_songlist_index(&self) -> Option<usize> {
-        self.songlists_table_state.selected()
+        self.songlists_table_state.selected().and_then(|selected| self.row_songlist_indices.get(selected).copied().flatten())
+    }
+
+    fn select_row(&mut self, row: usize) {
+        self.songlists_table_state.select(Some(row));
+        self.scrollbar_state = self.scrollbar_state.position(row);
+    }
+
+    /// 从 from 行开始按 step 方向查找第一个歌单行（跳过分组标题行）
+    fn find_songlist_row(&self, from: usize, step: isize) -> Option<usize> {
+        let mut row = from as isize;
+        while row >= 0 && (row as usize) < self.row_songlist_indices.len() {
+            if self.row_songlist_indices[row as usize].is_some() {
+                return Some(row as usize);
+            }
+            row += step;
+        }
+
+        None
+    }
+
+    /// 将用户歌单分为“创建的歌单”和“收藏的歌单”两组显示
+    fn set_grouped_rows(&mut self) {
+        let (created, subscribed): (Vec<usize>, Vec<usize>) = (0..self.songlists.len()).partition(|index| !self.songlists[*index].subscribed);
+
+        self.songlists_table_rows = Vec::new();
+        self.row_songlist_indices = Vec::new();
+        for (group_title, group) in [("创建的歌单", created), ("收藏的歌单", subscribed)] {
+            self.songlists_table_rows
+                .push(Row::new(vec![Cell::new(Span::styled(format!("{} ({})", group_title, group.len()), SECTION_HEADER_STYLE))]));
+            self.row_songlist_indices.push(None);
+
+            for index in group {
+                self.songlists_table_rows.push(songlist_to_row(&self.songlists[index]));
+                self.row_songlist_indices.push(Some(index));
+            }
+        }
     }
 }
 
@@ -59,39 +125,37 @@ impl<'a> Controller for SonglistsPanel<'a> {
     async fn update_model(&mut self) -> anyhow::Result<bool> {
         let mut result = Ok(false);
 
-        if self.songlists_table_rows.is_empty() {
+        if !self.is_manual_model {
             let player_guard = player.lock().await;
             let user_all_songlists = player_guard.songlists();
 
-            if let Some(login_account) = ncm_client.lock().await.login_account() {
-                self.username = login_account.nickname;
+            // 首次装载，或后台获取到了更多页的歌单、歌单被增删改
+            if self.songlists_table_rows.is_empty() || player_guard.songlists_revision() != self.songlists_revision {
+                self.songlists_revision = player_guard.songlists_revision();
+                if let Some(login_account) = ncm_client.lock().await.login_account() {
+                    self.title = format!("{}的歌单", login_account.nickname);
+                }
+                // 保留原有选中的歌单，歌单已不存在时重置，防止悬空
+                let selected_songlist_id = self.get_selected_songlist().map(|songlist| songlist.id);
+                self.songlists = user_all_songlists.clone();
+                self.set_grouped_rows();
+
+                let selected = selected_songlist_id
+                    .and_then(|id| self.songlists.iter().position(|songlist| songlist.id == id))
+                    .and_then(|index| self.row_songlist_indices.iter().position(|songlist_index| *songlist_index == Some(index)));
+                self.songlists_table_state.select(selected);
+
+                self.scrollbar_state = ScrollbarState::new(self.songlists_table_rows.len()).position(selected.unwrap_or(0));
+
+                result = Ok(true);
             }
-            self.songlists = user_all_songlists.clone();
-            self.songlists_table_rows = user_all_songlists
-                .iter()
-                .map(|songlist| {
-                    Row::from_iter(vec![
-                        Cell::new(songlist.name.clone()),
-                        Cell::new(songlist.creator.clone()),
-                        Cell::new(format!("{:>6}", songlist.songs_count)),
-                    ])
-                })
-                .collect();
-
-            drop(player_guard);
-
-            // 防止悬空
-            self.songlists_table_state.select(None);
-
-            self.scrollbar_state = ScrollbarState::new(self.songlists_table_rows.len());
-
-            result = Ok(true);
         }
 
-        if self.songlists_table_state.selected() == None && !self.songlists_table_rows.is_empty() {
-            self.songlists_table_state.select(Some(0));
-            self.scrollbar_state.first();
-            result = Ok(true);
+        if self.songlists_table_state.selected() == None {
+            if let Some(first_row) = self.find_songlist_row(0, 1) {
+                self.select_row(first_row);
+                result = Ok(true);
+            }
         }
 
         result
@@ -99,28 +163,31 @@ impl<'a> Controller for SonglistsPanel<'a> {
 
     async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
         match cmd {
+            // 上下移动时跳过分组标题行
             Command::Down => {
-                // 直接使用 select_next() 存在越界问题
-                if let (Some(selected), list_len) = (self.songlists_table_state.selected(), self.songlists_table_rows.len()) {
-                    if selected + 1 < list_len {
-                        self.songlists_table_state.select_next();
-                        self.scrollbar_state.next();
+                if let Some(selected) = self.songlists_table_state.selected() {
+                    if let Some(row) = self.find_songlist_row(selected + 1, 1) {
+                        self.select_row(row);
                     }
                 }
             },
             Command::Up => {
-                self.songlists_table_state.select_previous();
-                self.scrollbar_state.prev();
+                if let Some(selected) = self.songlists_table_state.selected() {
+                    if let Some(row) = selected.checked_sub(1).and_then(|from| self.find_songlist_row(from, -1)) {
+                        self.select_row(row);
+                    }
+                }
             },
             Command::EnterOrPlay => {},
             Command::GoToTop => {
-                self.songlists_table_state.select_first();
-                self.scrollbar_state.first();
+                if let Some(row) = self.find_songlist_row(0, 1) {
+                    self.select_row(row);
+                }
             },
             Command::GoToBottom => {
-                // 使用 select_last() 会越界
-                self.songlists_table_state.select(Some(self.songlists_table_rows.len() - 1));
-                self.scrollbar_state.last();
+                if let Some(row) = self.songlists_table_rows.len().checked_sub(1).and_then(|from| self.find_songlist_row(from, -1)) {
+                    self.select_row(row);
+                }
             },
             Command::SearchForward(_) => {},
             Command::SearchBackward(_) => {},
@@ -135,7 +202,7 @@ impl<'a> Controller for SonglistsPanel<'a> {
             .header(Row::new(vec![Cell::new("歌单"), Cell::new("创建者"), Cell::new("歌曲数")]).style(TABLE_HEADER_STYLE).height(1))
             .block({
                 let mut block = Block::default()
-                    .title(Line::from(format!("{}收藏的歌单", self.username)))
+                    .title(Line::from(self.title.clone()))
                     .title_bottom(Line::from("按下`Alt+Enter`开始播放选中歌单").centered())
                     .borders(Borders::ALL);
                 if self.focused_status == PanelFocusedStatus::Outside {
@@ -169,3 +236,12 @@ impl<'a> Controller for SonglistsPanel<'a> {
         frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
     }
 }
+
+#[inline]
+fn songlist_to_row<'a>(songlist: &Songlist) -> Row<'a> {
+    Row::from_iter(vec![
+        Cell::new(songlist.name.clone()),
+        Cell::new(songlist.creator.clone()),
+        Cell::new(format!("{:>6}", songlist.songs_count)),
+    ])
+}
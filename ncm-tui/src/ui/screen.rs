@@ -1,11 +1,112 @@
 //
+mod album_screen;
+mod artist_screen;
+mod comments_screen;
 mod help_screen;
+mod history_screen;
 mod login_screen;
 mod main_screen;
+mod podcast_screen;
+mod profile_screen;
+mod recommend_screen;
+mod search_screen;
 mod songlists_screen;
+mod toplists_screen;
 
 //
+pub use album_screen::AlbumScreen;
+pub use artist_screen::ArtistScreen;
+pub use comments_screen::CommentsScreen;
 pub use help_screen::HelpScreen;
+pub use history_screen::HistoryScreen;
 pub use login_screen::LoginScreen;
 pub use main_screen::MainScreen;
+pub use podcast_screen::PodcastScreen;
+pub use profile_screen::ProfileScreen;
+pub use recommend_screen::RecommendScreen;
+pub use search_screen::SearchScreen;
 pub use songlists_screen::SonglistsScreen;
+pub use toplists_screen::ToplistsScreen;
+
+use crate::config::{Command, ScreenEnum};
+use crate::ui::panel::PlaylistPanel;
+use crate::{command_queue, ncm_client, player};
+use anyhow::{anyhow, Context, Result};
+use ncm_api::model::{Song, Songlist};
+
+/// 返回 main_screen ，刷新播放列表显示并跳转到当前播放的歌曲
+async fn back_to_main_screen() {
+    let mut command_queue_guard = command_queue.lock().await;
+    command_queue_guard.push_back(Command::GotoScreen(ScreenEnum::Main));
+    command_queue_guard.push_back(Command::RefreshPlaylist);
+    command_queue_guard.push_back(Command::WhereIsThisSong);
+    drop(command_queue_guard);
+}
+
+/// 根据命令查看歌曲所属的专辑（`ShowAlbum`）或歌手（`ShowArtist`）
+///
+/// 歌曲有多位歌手且未指定序号时，返回包含歌手列表的提示，由用户通过 `artist <序号>` 选择
+async fn show_album_or_artist_of(cmd: &Command, song: &Song) -> Result<()> {
+    match cmd {
+        Command::ShowAlbum => command_queue.lock().await.push_back(Command::OpenAlbum(song.album_id)),
+        Command::ShowArtist(index) => {
+            let artist = match (index, song.artists.len()) {
+                (_, 0) => return Err(anyhow!("`{}`没有歌手信息", song.name)),
+                (None, 1) => &song.artists[0],
+                (None, _) => {
+                    let artist_names: Vec<String> = song.artists.iter().enumerate().map(|(i, artist)| format!("{}.{}", i + 1, artist.name)).collect();
+                    return Err(anyhow!("`{}`有多位歌手：{} ，输入`artist <序号>`选择", song.name, artist_names.join(" ")));
+                },
+                (Some(index), len) => song.artists.get(*index).ok_or_else(|| anyhow!("artist: 序号超出范围（1~{}）", len))?,
+            };
+
+            command_queue.lock().await.push_back(Command::OpenArtist(artist.id));
+        },
+        _ => {},
+    }
+
+    Ok(())
+}
+
+/// 根据命令（`MoveSongUp` / `MoveSongDown` / `MoveSongTo`）移动 playlist_panel 中所选的歌曲，并同步到云端
+///
+/// 先在本地（songlist 、playlist_panel 和播放器）调整顺序，云端拒绝时恢复原顺序并返回错误
+async fn move_songlist_song(cmd: &Command, songlist: &mut Songlist, playlist_panel: &mut PlaylistPanel<'_>) -> Result<()> {
+    if !ncm_client.lock().await.is_own_songlist(songlist) {
+        return Err(anyhow!("只能调整自己创建的歌单中歌曲的顺序"));
+    }
+
+    let len = songlist.songs.len();
+    let from = match playlist_panel.get_selected_index() {
+        Some(from) if from < len => from,
+        _ => return Ok(()),
+    };
+    let to = match cmd {
+        Command::MoveSongUp => from.saturating_sub(1),
+        Command::MoveSongDown => (from + 1).min(len - 1),
+        Command::MoveSongTo(to) if *to < len => *to,
+        Command::MoveSongTo(_) => return Err(anyhow!("move: 位置超出范围（1~{}）", len)),
+        _ => return Ok(()),
+    };
+    if from == to {
+        return Ok(());
+    }
+
+    move_song_locally(songlist, playlist_panel, from, to).await;
+
+    let song_ids: Vec<u64> = songlist.songs.iter().map(|song| song.id).collect();
+    if let Err(e) = ncm_client.lock().await.update_songlist_order(songlist.id, &song_ids).await {
+        move_song_locally(songlist, playlist_panel, to, from).await;
+
+        return Err(e).context("同步歌曲顺序失败，已恢复原顺序");
+    }
+
+    Ok(())
+}
+
+async fn move_song_locally(songlist: &mut Songlist, playlist_panel: &mut PlaylistPanel<'_>, from: usize, to: usize) {
+    let song = songlist.songs.remove(from);
+    songlist.songs.insert(to, song);
+    playlist_panel.move_song(from, to);
+    player.lock().await.move_songlist_song(songlist.id, from, to);
+}
@@ -0,0 +1,155 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{ncm_client, player};
+use anyhow::Result;
+use ncm_api::model::{Album, Song};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Line, Style, Text};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+pub struct AlbumScreen<'a> {
+    // model
+    album: Option<Album>,
+    //
+    album_songs_panel: PlaylistPanel<'a>,
+
+    // view
+    album_info_page: Paragraph<'a>,
+}
+
+impl<'a> AlbumScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            album: None,
+            album_songs_panel: PlaylistPanel::new(PanelFocusedStatus::Outside),
+            album_info_page: Paragraph::default(),
+        }
+    }
+
+    /// 专辑中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        self.album_songs_panel.get_selected_song()
+    }
+}
+
+impl<'a> Controller for AlbumScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        self.album_songs_panel.update_model().await
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+
+        let is_focused_inside = self.album_songs_panel.focused_status == PanelFocusedStatus::Inside;
+
+        match (cmd.clone(), is_focused_inside) {
+            // 加载专辑
+            (OpenAlbum(album_id), _) => {
+                let album = ncm_client.lock().await.get_album(album_id).await?;
+
+                self.album_songs_panel = PlaylistPanel::new(PanelFocusedStatus::Inside);
+                self.album_songs_panel.set_model(&album.name, &album.songs);
+                self.album = Some(album);
+            },
+
+            //
+            (Esc, true) => {
+                self.album_songs_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+            (Down | Up | EnterOrPlay, false) => {
+                self.album_songs_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+            (Down | Up | GoToTop | GoToBottom, _) => {
+                self.album_songs_panel.handle_event(cmd).await?;
+                self.album_songs_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+
+            // 以专辑为播放列表，播放选中歌曲
+            (EnterOrPlay, true) => {
+                if let Some(album) = self.album.as_ref() {
+                    player.lock().await.switch_to_songlist(&album.as_songlist());
+                    self.album_songs_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+            // 从第一首开始播放整张专辑
+            (Play, _) => {
+                if let Some(album) = self.album.as_ref() {
+                    let mut player_guard = player.lock().await;
+                    player_guard.switch_to_songlist(&album.as_songlist());
+                    player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                    drop(player_guard);
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            // 将选中歌曲加入待播队列
+            (Enqueue, _) => {
+                if let Some(song) = self.album_songs_panel.get_selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            // 查看选中歌曲的歌手
+            (ShowArtist(_), _) => {
+                if let Some(song) = self.album_songs_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.album_songs_panel.update_view(style);
+
+        let album_info_text = match self.album.as_ref() {
+            Some(album) => {
+                let mut lines = vec![
+                    Line::from(format!("\u{1F4DA}{}", album.name)),
+                    Line::from(format!("\u{1F3A4}{}", album.artist)),
+                    Line::from(format!("发行时间: {}", album.publish_date())),
+                ];
+                if !album.company.is_empty() {
+                    lines.push(Line::from(format!("发行公司: {}", album.company)));
+                }
+                lines.push(Line::from(format!("歌曲数: {}", album.songs.len())));
+                lines.push(Line::from(""));
+                lines.extend(album.description.split('\n').map(|s| Line::from(s.to_owned())));
+
+                Text::from(lines)
+            },
+            None => Text::from(Line::from("在播放列表中选中歌曲后按下`a`查看所属专辑").centered()),
+        };
+
+        self.album_info_page = Paragraph::new(album_info_text)
+            .block(Block::default().title("专辑").title_bottom(Line::from("按下`Alt+Enter`播放整张专辑").centered()).borders(Borders::ALL))
+            .wrap(Wrap { trim: false })
+            .style(*style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+            .split(chunk);
+
+        // 在左侧渲染专辑信息
+        frame.render_widget(&self.album_info_page, chunks[0]);
+
+        // 在右侧渲染专辑内的歌曲
+        self.album_songs_panel.draw(frame, chunks[1]);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::config::style::*;
+use crate::config::Command;
+use crate::ui::panel::{AlbumsPanel, ArtistsPanel, PanelFocusedStatus, PlaylistPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{command_queue, ncm_client, player};
+use anyhow::Result;
+use ncm_api::model::{Album, Artist, Song, Songlist};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Line, Style, Text};
+use ratatui::widgets::{Block, Borders, Paragraph, Tabs, Wrap};
+use ratatui::Frame;
+
+/// 每次请求获取的专辑数
+const ARTIST_ALBUMS_PAGE_SIZE: usize = 30;
+
+#[derive(PartialEq, Clone, Copy)]
+enum ArtistTab {
+    TopSongs,
+    Albums,
+    SimilarArtists,
+}
+
+/// tab 顺序
+const ARTIST_TABS: [ArtistTab; 3] = [ArtistTab::TopSongs, ArtistTab::Albums, ArtistTab::SimilarArtists];
+
+pub struct ArtistScreen<'a> {
+    current_tab: usize,
+    //
+    artist: Option<Artist>,
+    albums: Vec<Album>,
+    albums_has_more: bool,
+    similar_artists: Option<Vec<Artist>>, // 切换到对应 tab 时才加载
+    //
+    top_songs_panel: PlaylistPanel<'a>,
+    albums_panel: AlbumsPanel<'a>,
+    similar_artists_panel: ArtistsPanel<'a>,
+
+    // view
+    tabs: Tabs<'a>,
+    artist_info_page: Paragraph<'a>,
+}
+
+impl<'a> ArtistScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_tab: 0,
+            artist: None,
+            albums: Vec::new(),
+            albums_has_more: false,
+            similar_artists: None,
+            top_songs_panel: PlaylistPanel::new(PanelFocusedStatus::Outside),
+            albums_panel: AlbumsPanel::new(PanelFocusedStatus::Outside),
+            similar_artists_panel: ArtistsPanel::new(PanelFocusedStatus::Outside),
+            tabs: Tabs::default(),
+            artist_info_page: Paragraph::default(),
+        }
+    }
+
+    /// 热门歌曲中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.get_selected_song(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Controller for ArtistScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.update_model().await,
+            ArtistTab::Albums => self.albums_panel.update_model().await,
+            ArtistTab::SimilarArtists => self.similar_artists_panel.update_model().await,
+        }
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+
+        let is_focused_inside = self.is_current_panel_focused_inside();
+
+        match (cmd.clone(), is_focused_inside) {
+            // 加载歌手
+            (OpenArtist(artist_id), _) => {
+                self.open_artist(artist_id).await?;
+            },
+
+            //
+            (Esc, true) => {
+                self.set_current_focused_status(PanelFocusedStatus::Outside);
+            },
+            (Down | Up | EnterOrPlay, false) => {
+                self.set_current_focused_status(PanelFocusedStatus::Inside);
+            },
+            (Down, true) => {
+                // 到达底部时加载下一页专辑
+                if self.current_artist_tab() == ArtistTab::Albums && self.albums_has_more && self.albums_panel.get_selected_index() == Some(self.albums.len().saturating_sub(1)) {
+                    self.load_next_albums_page().await?;
+                }
+                self.current_panel_handle_event(cmd).await?;
+            },
+            (Up, true) => {
+                self.current_panel_handle_event(cmd).await?;
+            },
+            (GoToTop | GoToBottom, _) => {
+                self.current_panel_handle_event(cmd).await?;
+                self.set_current_focused_status(PanelFocusedStatus::Inside);
+            },
+
+            // 左右切换 tab
+            (NextPanel, _) => {
+                if self.current_tab + 1 < ARTIST_TABS.len() {
+                    self.switch_tab(self.current_tab + 1).await?;
+                }
+            },
+            (PrevPanel, _) => {
+                if self.current_tab > 0 {
+                    self.switch_tab(self.current_tab - 1).await?;
+                }
+            },
+
+            //
+            (EnterOrPlay, true) => match self.current_artist_tab() {
+                // 以热门歌曲为播放列表，播放选中歌曲
+                ArtistTab::TopSongs => {
+                    if let Some(artist) = self.artist.as_ref() {
+                        player.lock().await.switch_to_songlist(&artist.as_songlist());
+                        self.top_songs_panel.handle_event(cmd).await?;
+
+                        back_to_main_screen().await;
+                    }
+                },
+                // 进入专辑页面
+                ArtistTab::Albums => {
+                    if let Some(album) = self.albums_panel.get_selected_album() {
+                        command_queue.lock().await.push_back(OpenAlbum(album.id));
+                    }
+                },
+                // 进入相似歌手页面
+                ArtistTab::SimilarArtists => {
+                    if let Some(artist) = self.similar_artists_panel.get_selected_artist() {
+                        command_queue.lock().await.push_back(OpenArtist(artist.id));
+                    }
+                },
+            },
+            // 从第一首开始播放所选内容
+            (Play, _) => {
+                if let Some(songlist) = self.selected_as_songlist().await? {
+                    let mut player_guard = player.lock().await;
+                    player_guard.switch_to_songlist(&songlist);
+                    player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                    drop(player_guard);
+
+                    back_to_main_screen().await;
+                }
+            },
+            // 将所选内容加入待播队列（热门歌曲 tab 只加入选中的单曲）
+            (Enqueue, _) => {
+                let songs_to_enqueue: Vec<Song> = match self.current_artist_tab() {
+                    ArtistTab::TopSongs => self.top_songs_panel.get_selected_song().into_iter().collect(),
+                    _ => self.selected_as_songlist().await?.map(|songlist| songlist.songs).unwrap_or_default(),
+                };
+                player.lock().await.enqueue_songs(&songs_to_enqueue);
+            },
+
+            // 查看所选歌曲所属专辑
+            (ShowAlbum, _) if self.current_artist_tab() == ArtistTab::TopSongs => {
+                if let Some(song) = self.top_songs_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        let artist_name = self.artist.as_ref().map(|artist| artist.name.clone()).unwrap_or_default();
+
+        self.tabs = Tabs::new(ARTIST_TABS.iter().map(|artist_tab| artist_tab_name(artist_tab)).collect::<Vec<&str>>())
+            .block(Block::default().title(Line::from(format!("歌手: {}", artist_name))).borders(Borders::ALL))
+            .highlight_style(ITEM_SELECTED_STYLE)
+            .select(self.current_tab)
+            .style(*style);
+
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.update_view(style),
+            ArtistTab::Albums => self.albums_panel.update_view(style),
+            ArtistTab::SimilarArtists => self.similar_artists_panel.update_view(style),
+        }
+
+        let artist_info_text = match self.artist.as_ref() {
+            Some(artist) => {
+                let mut lines = vec![
+                    Line::from(format!("\u{1F3A4}{}", artist.name)),
+                    Line::from(format!("专辑数: {}", artist.albums_count)),
+                    Line::from(format!("歌曲数: {}", artist.songs_count)),
+                    Line::from(""),
+                ];
+                lines.extend(artist.brief_desc.split('\n').map(|s| Line::from(s.to_owned())));
+
+                Text::from(lines)
+            },
+            None => Text::from(Line::from("在播放列表中选中歌曲后按下`s`查看歌手主页").centered()),
+        };
+
+        self.artist_info_page = Paragraph::new(artist_info_text)
+            .block(
+                Block::default()
+                    .title("歌手")
+                    .title_bottom(Line::from("按下`Alt+Enter`播放，按下`e`加入待播队列").centered())
+                    .borders(Borders::ALL),
+            )
+            .wrap(Wrap { trim: false })
+            .style(*style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+            .split(chunk);
+
+        // 在左侧渲染歌手信息
+        frame.render_widget(&self.artist_info_page, chunks[0]);
+
+        // 右侧上方渲染 tabs ，下方渲染当前 tab 对应的内容
+        let right_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)].as_ref())
+            .split(chunks[1]);
+
+        frame.render_widget(&self.tabs, right_chunks[0]);
+
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.draw(frame, right_chunks[1]),
+            ArtistTab::Albums => self.albums_panel.draw(frame, right_chunks[1]),
+            ArtistTab::SimilarArtists => self.similar_artists_panel.draw(frame, right_chunks[1]),
+        }
+    }
+}
+
+/// private
+impl<'a> ArtistScreen<'a> {
+    fn current_artist_tab(&self) -> ArtistTab {
+        ARTIST_TABS[self.current_tab]
+    }
+
+    /// 加载歌手详情、热门歌曲和第一页专辑，相似歌手在切换到对应 tab 时加载
+    async fn open_artist(&mut self, artist_id: u64) -> Result<()> {
+        let ncm_client_guard = ncm_client.lock().await;
+        let mut artist = ncm_client_guard.get_artist_detail(artist_id).await?;
+        artist.top_songs = ncm_client_guard.get_artist_top_songs(artist_id).await?;
+        drop(ncm_client_guard);
+
+        *self = Self::new(&Style::default());
+
+        self.top_songs_panel.set_model(&format!("{} 热门歌曲", artist.name), &artist.top_songs);
+        self.artist = Some(artist);
+        self.load_next_albums_page().await?;
+
+        self.set_current_focused_status(PanelFocusedStatus::Inside);
+
+        Ok(())
+    }
+
+    /// 切换 tab
+    async fn switch_tab(&mut self, to_tab: usize) -> Result<()> {
+        self.current_tab = to_tab;
+
+        if self.current_artist_tab() == ArtistTab::SimilarArtists && self.similar_artists.is_none() {
+            if let Some(artist) = self.artist.as_ref() {
+                // 未登录时获取失败，视为无相似歌手
+                let similar_artists = ncm_client.lock().await.get_similar_artists(artist.id).await.unwrap_or_default();
+                self.similar_artists_panel.set_model(&format!("与 {} 相似的歌手", artist.name), &similar_artists);
+                self.similar_artists = Some(similar_artists);
+            }
+        }
+
+        self.set_current_focused_status(PanelFocusedStatus::Outside);
+
+        Ok(())
+    }
+
+    /// 获取下一页专辑，追加到已有专辑之后
+    async fn load_next_albums_page(&mut self) -> Result<()> {
+        if let Some(artist) = self.artist.as_ref() {
+            let (albums, has_more) = ncm_client.lock().await.get_artist_albums(artist.id, self.albums.len(), ARTIST_ALBUMS_PAGE_SIZE).await?;
+            self.albums.extend(albums);
+            self.albums_has_more = has_more;
+
+            // 防止 set_model 后选中项回到顶部
+            let selected = self.albums_panel.get_selected_index();
+            self.albums_panel.set_model(&format!("{} 的专辑", artist.name), &self.albums);
+            if let Some(selected) = selected {
+                self.albums_panel.select(selected);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 当前 tab 所选内容组成的歌单：热门歌曲、选中的专辑或选中的相似歌手的热门歌曲
+    async fn selected_as_songlist(&self) -> Result<Option<Songlist>> {
+        let songlist = match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.artist.as_ref().map(|artist| artist.as_songlist()),
+            ArtistTab::Albums => match self.albums_panel.get_selected_album() {
+                Some(album) => Some(ncm_client.lock().await.get_album(album.id).await?.as_songlist()),
+                None => None,
+            },
+            ArtistTab::SimilarArtists => match self.similar_artists_panel.get_selected_artist() {
+                Some(mut artist) => {
+                    artist.top_songs = ncm_client.lock().await.get_artist_top_songs(artist.id).await?;
+                    Some(artist.as_songlist())
+                },
+                None => None,
+            },
+        };
+
+        Ok(songlist)
+    }
+
+    async fn current_panel_handle_event(&mut self, cmd: Command) -> Result<bool> {
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.handle_event(cmd).await,
+            ArtistTab::Albums => self.albums_panel.handle_event(cmd).await,
+            ArtistTab::SimilarArtists => self.similar_artists_panel.handle_event(cmd).await,
+        }
+    }
+
+    fn is_current_panel_focused_inside(&self) -> bool {
+        let focused_status = match self.current_artist_tab() {
+            ArtistTab::TopSongs => &self.top_songs_panel.focused_status,
+            ArtistTab::Albums => &self.albums_panel.focused_status,
+            ArtistTab::SimilarArtists => &self.similar_artists_panel.focused_status,
+        };
+
+        *focused_status == PanelFocusedStatus::Inside
+    }
+
+    fn set_current_focused_status(&mut self, focused_status: PanelFocusedStatus) {
+        match self.current_artist_tab() {
+            ArtistTab::TopSongs => self.top_songs_panel.focused_status = focused_status,
+            ArtistTab::Albums => self.albums_panel.focused_status = focused_status,
+            ArtistTab::SimilarArtists => self.similar_artists_panel.focused_status = focused_status,
+        }
+    }
+}
+
+#[inline]
+fn artist_tab_name(artist_tab: &ArtistTab) -> &'static str {
+    match artist_tab {
+        ArtistTab::TopSongs => "热门歌曲",
+        ArtistTab::Albums => "专辑",
+        ArtistTab::SimilarArtists => "相似歌手",
+    }
+}
@@ -0,0 +1,351 @@
+use crate::config::Command;
+use crate::ncm_client;
+use crate::ui::panel::{CommentsPanel, PanelFocusedStatus};
+use crate::ui::Controller;
+use anyhow::Result;
+use ncm_api::model::{Comment, Song};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Line, Style, Text};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+/// 获取的热门评论数量
+const HOT_COMMENTS_LIMIT: usize = 20;
+/// 每页获取的最新评论数量
+const LATEST_COMMENTS_PAGE_SIZE: usize = 30;
+
+#[derive(PartialEq)]
+enum Panels {
+    HotComments,
+    LatestComments,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    HotCommentsOutside,
+    HotCommentsInside,
+    LatestCommentsOutside,
+    LatestCommentsInside,
+}
+
+pub struct CommentsScreen<'a> {
+    // model
+    current_focus_panel: FocusPanel,
+    //
+    song: Option<Song>,
+    hot_comments: Vec<Comment>,
+    latest_comments_pages: Vec<Vec<Comment>>, // 已加载的最新评论，每页一项
+    current_page_index: usize,
+    latest_comments_has_more: bool,
+    //
+    hot_comments_panel: CommentsPanel<'a>,
+    latest_comments_panel: CommentsPanel<'a>,
+
+    // view
+    comment_detail_page: Paragraph<'a>,
+}
+
+impl<'a> CommentsScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::LatestCommentsOutside,
+            song: None,
+            hot_comments: Vec::new(),
+            latest_comments_pages: Vec::new(),
+            current_page_index: 0,
+            latest_comments_has_more: false,
+            hot_comments_panel: CommentsPanel::new(PanelFocusedStatus::Nop),
+            latest_comments_panel: CommentsPanel::new(PanelFocusedStatus::Outside),
+            comment_detail_page: Paragraph::default(),
+        }
+    }
+
+    /// 评论所属的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        self.song.clone()
+    }
+}
+
+impl<'a> Controller for CommentsScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        let mut result = Ok(false);
+
+        if self.hot_comments_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        if self.latest_comments_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            // 加载歌曲的热门评论和第一页最新评论
+            (OpenComments(song), _) => {
+                self.open_comments(*song).await?;
+            },
+
+            //
+            (Esc, HotCommentsInside) => {
+                self.focus_panel_outside(Panels::HotComments);
+            },
+            (Esc, LatestCommentsInside) => {
+                self.focus_panel_outside(Panels::LatestComments);
+            },
+
+            //
+            (Down | Up | EnterOrPlay, HotCommentsOutside) => {
+                self.focus_panel_inside(Panels::HotComments);
+            },
+            (Down | Up | EnterOrPlay, LatestCommentsOutside) => {
+                self.focus_panel_inside(Panels::LatestComments);
+            },
+            (Down | Up, HotCommentsInside) => {
+                self.hot_comments_panel.handle_event(cmd).await?;
+            },
+            // 在最后一条评论处向下时翻到下一页
+            (Down, LatestCommentsInside) => {
+                let page_len = self.latest_comments_pages.get(self.current_page_index).map_or(0, Vec::len);
+                if self.latest_comments_panel.get_selected_index() == Some(page_len.saturating_sub(1)) {
+                    self.goto_next_page().await?;
+                } else {
+                    self.latest_comments_panel.handle_event(cmd).await?;
+                }
+            },
+            // 在第一条评论处向上时翻到上一页
+            (Up, LatestCommentsInside) => {
+                if self.latest_comments_panel.get_selected_index() == Some(0) && self.current_page_index > 0 {
+                    self.goto_prev_page();
+                } else {
+                    self.latest_comments_panel.handle_event(cmd).await?;
+                }
+            },
+
+            //
+            (NextPanel, HotCommentsOutside) => {
+                self.focus_panel_outside(Panels::LatestComments);
+            },
+            (PrevPanel, LatestCommentsOutside) => {
+                self.focus_panel_outside(Panels::HotComments);
+            },
+
+            //
+            (GoToTop | GoToBottom, HotCommentsOutside | HotCommentsInside) => {
+                self.hot_comments_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::HotComments);
+            },
+            (GoToTop | GoToBottom, LatestCommentsOutside | LatestCommentsInside) => {
+                self.latest_comments_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::LatestComments);
+            },
+
+            // 给所选评论点赞或取消点赞
+            (ToggleLikeComment, _) => {
+                self.toggle_like_selected_comment().await?;
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.hot_comments_panel.update_view(style);
+
+        self.latest_comments_panel.update_view(style);
+
+        // 所选评论的完整内容
+        let comment_detail_text = match self.selected_comment() {
+            Some(comment) => {
+                let mut lines = vec![
+                    Line::from(format!(
+                        "{}  {}  {} {}",
+                        comment.user_name,
+                        comment.time_str(),
+                        if comment.liked { '\u{2665}' } else { '\u{2661}' },
+                        comment.liked_count
+                    )),
+                    Line::from(""),
+                ];
+                lines.extend(comment.content.split('\n').map(|s| Line::from(s.to_owned())));
+
+                Text::from(lines)
+            },
+            None => Text::from(Line::from("在播放列表中选中歌曲后按下`c`查看评论").centered()),
+        };
+        let title = match self.song.as_ref() {
+            Some(song) => format!("{} - {}", song.name, song.singer()),
+            None => String::from("评论"),
+        };
+
+        self.comment_detail_page = Paragraph::new(comment_detail_text)
+            .block(
+                Block::default()
+                    .title(title)
+                    .title_bottom(Line::from("`j`/`k`在评论间移动，到达页首/页尾时翻页，按下`F`点赞").centered())
+                    .borders(Borders::ALL),
+            )
+            .wrap(Wrap { trim: false })
+            .style(*style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 上方为评论列表，下方为所选评论的完整内容
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
+            .split(chunk);
+        let list_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(chunks[0]);
+
+        self.hot_comments_panel.draw(frame, list_chunks[0]);
+
+        self.latest_comments_panel.draw(frame, list_chunks[1]);
+
+        frame.render_widget(&self.comment_detail_page, chunks[1]);
+    }
+}
+
+/// private
+impl<'a> CommentsScreen<'a> {
+    async fn open_comments(&mut self, song: Song) -> Result<()> {
+        let ncm_client_guard = ncm_client.lock().await;
+        let hot_comments = ncm_client_guard.get_song_hot_comments(song.id, HOT_COMMENTS_LIMIT).await?;
+        let (latest_comments, has_more) = ncm_client_guard.get_song_comments(song.id, 0, LATEST_COMMENTS_PAGE_SIZE).await?;
+        drop(ncm_client_guard);
+
+        self.hot_comments_panel.set_model("热门评论", &hot_comments);
+        self.hot_comments = hot_comments;
+        self.latest_comments_pages = vec![latest_comments];
+        self.current_page_index = 0;
+        self.latest_comments_has_more = has_more;
+        self.set_latest_comments_model();
+        self.song = Some(song);
+
+        self.focus_panel_outside(Panels::LatestComments);
+
+        Ok(())
+    }
+
+    /// 翻到下一页，尚未加载时获取
+    async fn goto_next_page(&mut self) -> Result<()> {
+        if self.current_page_index + 1 >= self.latest_comments_pages.len() {
+            let song_id = match (self.song.as_ref(), self.latest_comments_has_more) {
+                (Some(song), true) => song.id,
+                _ => return Ok(()),
+            };
+
+            let offset = self.latest_comments_pages.len() * LATEST_COMMENTS_PAGE_SIZE;
+            let (latest_comments, has_more) = ncm_client.lock().await.get_song_comments(song_id, offset, LATEST_COMMENTS_PAGE_SIZE).await?;
+            self.latest_comments_has_more = has_more;
+            if latest_comments.is_empty() {
+                return Ok(());
+            }
+            self.latest_comments_pages.push(latest_comments);
+        }
+
+        self.current_page_index += 1;
+        self.set_latest_comments_model();
+        self.latest_comments_panel.select(0);
+
+        Ok(())
+    }
+
+    /// 翻到上一页，选中该页最后一条评论
+    fn goto_prev_page(&mut self) {
+        self.current_page_index -= 1;
+        self.set_latest_comments_model();
+
+        let page_len = self.latest_comments_pages[self.current_page_index].len();
+        self.latest_comments_panel.select(page_len.saturating_sub(1));
+    }
+
+    fn set_latest_comments_model(&mut self) {
+        if let Some(latest_comments) = self.latest_comments_pages.get(self.current_page_index) {
+            self.latest_comments_panel.set_model(&format!("最新评论 (第{}页)", self.current_page_index + 1), latest_comments);
+        }
+    }
+
+    fn selected_comment(&self) -> Option<Comment> {
+        match self.current_focus_panel {
+            FocusPanel::HotCommentsOutside | FocusPanel::HotCommentsInside => self.hot_comments_panel.get_selected_comment(),
+            FocusPanel::LatestCommentsOutside | FocusPanel::LatestCommentsInside => self.latest_comments_panel.get_selected_comment(),
+        }
+    }
+
+    async fn toggle_like_selected_comment(&mut self) -> Result<()> {
+        let (song_id, mut comment) = match (self.song.as_ref(), self.selected_comment()) {
+            (Some(song), Some(comment)) => (song.id, comment),
+            _ => return Ok(()),
+        };
+
+        ncm_client.lock().await.like_comment(song_id, comment.id, !comment.liked).await?;
+
+        comment.liked = !comment.liked;
+        if comment.liked {
+            comment.liked_count += 1;
+        } else {
+            comment.liked_count = comment.liked_count.saturating_sub(1);
+        }
+
+        // 同步到已加载的评论和面板
+        match self.current_focus_panel {
+            FocusPanel::HotCommentsOutside | FocusPanel::HotCommentsInside => {
+                if let Some(index) = self.hot_comments_panel.get_selected_index() {
+                    self.hot_comments[index] = comment.clone();
+                    self.hot_comments_panel.set_comment(index, &comment);
+                }
+            },
+            FocusPanel::LatestCommentsOutside | FocusPanel::LatestCommentsInside => {
+                if let Some(index) = self.latest_comments_panel.get_selected_index() {
+                    self.latest_comments_pages[self.current_page_index][index] = comment.clone();
+                    self.latest_comments_panel.set_comment(index, &comment);
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::HotComments => {
+                self.current_focus_panel = FocusPanel::HotCommentsOutside;
+                self.hot_comments_panel.focused_status = PanelFocusedStatus::Outside;
+                self.latest_comments_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::LatestComments => {
+                self.current_focus_panel = FocusPanel::LatestCommentsOutside;
+                self.hot_comments_panel.focused_status = PanelFocusedStatus::Nop;
+                self.latest_comments_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::HotComments => {
+                self.current_focus_panel = FocusPanel::HotCommentsInside;
+                self.hot_comments_panel.focused_status = PanelFocusedStatus::Inside;
+                self.latest_comments_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::LatestComments => {
+                self.current_focus_panel = FocusPanel::LatestCommentsInside;
+                self.hot_comments_panel.focused_status = PanelFocusedStatus::Nop;
+                self.latest_comments_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
@@ -22,14 +22,27 @@ impl<'a> HelpScreen<'a> {
             Previous Panel:                         {}\n\
             Next Panel:                             {}\n\
             Go To Main Screen:                      {}\n\
+            Go To Search Screen:                    {}\n\
+            Go To Recommend Screen:                 {}\n\
+            Go To Toplists Screen:                  {}\n\
+            Go To Podcast Screen:                   {}\n\
+            Go To Recently Played Screen:           {}\n\
+            Go To User Profile Screen:              {}\n\
             Go To Help Screen (Here):               {}\n\
             Play Next Song:                         {}\n\
             Play Previous Song:                     {}\n\
+            Like / Unlike Current Song:             {}\n\
+            Show Album Of Selected Song:            {}\n\
+            Show Artist Of Selected Song:           {}\n\
+            Show Comments Of Selected Song:         {}\n\
+            Like / Unlike Selected Comment:         {}\n\
+            Add Selected To Play Queue:             {}\n\
+            Move Selected Song Up / Down:           {}\n\
             *Switch To Command Line Mode:           {}\n\
             Search Forward:                         {}\n\
             Search Backward:                        {}\n\
             Quit:                                   {}",
-            "↑ / k", "↓ / j", "\u{2423} (Space)", "←", "→", "1", "0 / F1", ">", "<", ":", "/", "?", "q",
+            "↑ / k", "↓ / j", "\u{2423} (Space)", "←", "→", "1", "3", "4", "5", "6", "7", "8", "0 / F1", ">", "<", "f", "a", "s", "c", "F", "e", "K / J", ":", "/", "?", "q",
         ));
         let normal_mode_help_page = Paragraph::new(normal_mode_help_text)
             .block(Block::default().title("普通模式").borders(Borders::ALL))
@@ -43,6 +56,11 @@ impl<'a> HelpScreen<'a> {
             Go To Help Screen (Here):               {}\n\
             Go To Login Screen:                     {}\n\
             Logout:                                 {}\n\
+            Manage Profiles:                        {}\n\
+            |_ list profiles:                       {}\n\
+            |_ switch to profile:                   {}\n\
+            |_ add & switch to profile:             {}\n\
+            |_ remove profile:                      {}\n\
             Set Volume:                             {} (e.g. `vol 20` will set volume at 20%)\n\
             Mute:                                   {}\n\
             Set Play Mode:                          {}\n\
@@ -50,20 +68,49 @@ impl<'a> HelpScreen<'a> {
             |_ single repeat mode:                  {}\n\
             |_ list repeat mode:                    {}\n\
             |_ shuffle mode:                        {}\n\
+            |_ personal fm mode:                    {}\n\
+            Show / Set Preferred Quality:           {}\n\
+            |_ for this session:                    {}\n\
+            |_ for current song only:               {}\n\
+            |_ reset:                               {}\n\
             Play Next Song:                         {}\n\
             Play Previous Song:                     {}\n\
-            Start Auto Play:                        {} (Only under `list repeat mode` or `shuffle mode`)\n\
+            Start Auto Play:                        {} (Only under `list repeat mode`, `shuffle mode` or `personal fm mode`)\n\
+            Trash Current Song:                     {} (Only under `personal fm mode`)\n\
+            Heart Mode From Current Song:           {}\n\
+            Like Current Song:                      {}\n\
+            Unlike Current Song:                    {}\n\
+            Show Album Of Selected Song:            {}\n\
+            Show Artist Of Selected Song:           {}\n\
+            Show Comments Of Selected Song:         {}\n\
+            Like / Unlike Selected Comment:         {}\n\
+            Add Selected To Play Queue:             {}\n\
+            Manage Playlists:                       {}\n\
+            |_ create playlist:                     {}\n\
+            |_ rename selected playlist:            {}\n\
+            |_ delete selected playlist:            {}\n\
+            |_ add selected song to playlist:       {}\n\
+            |_ remove selected song from playlist:  {}\n\
+            |_ move selected song in playlist:      {}\n\
+            |_ subscribe selected playlist:         {}\n\
+            |_ unsubscribe selected playlist:       {}\n\
             Jump To Current Song In Playlist:       {}\n\
             Jump To Top:                            {}\n\
             Jump To Bottom:                         {}\n\
             Search Forward:                         {}\n\
-            Search Backward:                        {}",
+            Search Backward:                        {}\n\
+            Global Search:                          {}",
             "q / quit / exit",
-            "screen 0 / 1",
-            "screen help / main",
+            "screen 0 / 1 / 2 / 3 / 4 / 5 / 6 / 7 / 8",
+            "screen help / main / playlist / search / recommend / toplist / podcast / history / user",
             "h / help",
             "l / login",
-            "logout",
+            "logout [-p / --purge]",
+            "profile",
+            "profile list",
+            "profile switch xxx",
+            "profile add xxx",
+            "profile remove xxx",
             "vol / volume",
             "mute",
             "mode",
@@ -71,14 +118,38 @@ impl<'a> HelpScreen<'a> {
             "mode sr / single-repeat",
             "mode lr / list-repeat",
             "mode s / shuf / shuffle",
+            "mode fm / personal-fm",
+            "quality",
+            "quality standard / higher / exhigh / lossless / hires / jyeffect / sky / dolby / jymaster",
+            "quality xxx -s / --song",
+            "quality reset [-s / --song]",
             "next",
             "prev / previous",
             "start",
+            "trash",
+            "heartmode / heart",
+            "like",
+            "unlike",
+            "album",
+            "artist [N]",
+            "comments",
+            "likecomment",
+            "enqueue",
+            "playlist",
+            "playlist new xxx",
+            "playlist rename xxx",
+            "playlist delete / rm",
+            "add-to xxx",
+            "remove",
+            "move up / down / N",
+            "subscribe",
+            "unsubscribe",
             "where this",
             "top",
             "bottom",
             "/ xxx",
             "? xxx",
+            "search xxx",
         ));
         let commandline_mode_help_page = Paragraph::new(commandline_mode_help_text)
             .block(Block::default().title("命令行模式").borders(Borders::ALL))
@@ -0,0 +1,146 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{ncm_client, player};
+use anyhow::Result;
+use log::error;
+use ncm_api::model::{Song, Songlist};
+use ratatui::layout::Rect;
+use ratatui::prelude::Style;
+use ratatui::Frame;
+
+pub struct HistoryScreen<'a> {
+    // model
+    is_loaded: bool,
+    recent_songlist: Option<Songlist>,
+    //
+    recent_songs_panel: PlaylistPanel<'a>,
+}
+
+impl<'a> HistoryScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            is_loaded: false,
+            recent_songlist: None,
+            recent_songs_panel: PlaylistPanel::new(PanelFocusedStatus::Outside),
+        }
+    }
+
+    /// 最近播放中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        self.recent_songs_panel.get_selected_song()
+    }
+}
+
+impl<'a> Controller for HistoryScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        let mut result = Ok(false);
+
+        // 首次进入时获取播放记录（离开界面时会重建，再次进入即刷新）
+        if !self.is_loaded {
+            self.is_loaded = true;
+            self.load_recent_songlist().await;
+            result = Ok(true);
+        }
+
+        if self.recent_songs_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+
+        let is_focused_inside = self.recent_songs_panel.focused_status == PanelFocusedStatus::Inside;
+
+        match (cmd.clone(), is_focused_inside) {
+            //
+            (Esc, true) => {
+                self.recent_songs_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+            (Down | Up | EnterOrPlay, false) => {
+                self.recent_songs_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+            (Down | Up | GoToTop | GoToBottom | SearchForward(_) | SearchBackward(_), _) => {
+                self.recent_songs_panel.handle_event(cmd).await?;
+                self.recent_songs_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+
+            // 以最近播放为播放列表，播放选中歌曲
+            (EnterOrPlay, true) => {
+                if let Some(recent_songlist) = self.recent_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(recent_songlist);
+                    self.recent_songs_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+            // 从最近一首开始播放
+            (Play, _) => {
+                if let Some(recent_songlist) = self.recent_songlist.as_ref().filter(|songlist| !songlist.songs.is_empty()) {
+                    let mut player_guard = player.lock().await;
+                    player_guard.switch_to_songlist(recent_songlist);
+                    player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                    drop(player_guard);
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            // 将选中歌曲加入待播队列
+            (Enqueue, _) => {
+                if let Some(song) = self.recent_songs_panel.get_selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), _) => {
+                if let Some(song) = self.recent_songs_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.recent_songs_panel.update_view(style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        self.recent_songs_panel.draw(frame, chunk);
+    }
+}
+
+/// private
+impl<'a> HistoryScreen<'a> {
+    /// 获取账号的最近播放，失败时在面板标题中提示
+    async fn load_recent_songlist(&mut self) {
+        let ncm_client_guard = ncm_client.lock().await;
+        if !ncm_client_guard.is_login() {
+            self.recent_songs_panel.set_model(&String::from("最近播放 (请先登录)"), &Vec::new());
+            return;
+        }
+
+        match ncm_client_guard.get_recent_songlist().await {
+            Ok(recent_songlist) => {
+                self.recent_songs_panel.set_model(&format!("最近播放 ({}首)", recent_songlist.songs_count), &recent_songlist.songs);
+                self.recent_songlist = Some(recent_songlist);
+            },
+            Err(e) => {
+                error!("failed to load recent songs: {:?}", e);
+                self.recent_songs_panel.set_model(&format!("最近播放 (加载失败: {})", e), &Vec::new());
+            },
+        }
+    }
+}
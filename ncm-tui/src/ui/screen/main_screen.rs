@@ -1,7 +1,10 @@
 use crate::config::Command;
 use crate::ui::panel::{LyricPanel, PanelFocusedStatus, PlaylistPanel};
+use crate::ui::screen::{move_songlist_song, show_album_or_artist_of};
 use crate::ui::Controller;
-use anyhow::Result;
+use crate::{actions, player};
+use anyhow::{anyhow, Result};
+use ncm_api::model::Song;
 use ratatui::layout::Rect;
 use ratatui::prelude::*;
 use ratatui::Frame;
@@ -35,6 +38,14 @@ impl<'a> MainScreen<'a> {
             lyric_panel: LyricPanel::new(PanelFocusedStatus::Nop),
         }
     }
+
+    /// 播放列表中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::PlaylistOutside | FocusPanel::PlaylistInside => self.playlist_panel.get_selected_song(),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> Controller for MainScreen<'a> {
@@ -124,6 +135,23 @@ impl<'a> Controller for MainScreen<'a> {
                 self.playlist_panel.handle_event(cmd).await?;
             },
             //
+            (ShowAlbum | ShowArtist(_), PlaylistOutside | PlaylistInside) => {
+                if let Some(song) = self.playlist_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+            (ShowAlbum | ShowArtist(_), LyricOutside | LyricInside) => {
+                let current_song = player.lock().await.current_song().clone();
+                if let Some(song) = current_song {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+            // 调整所选歌曲在当前播放的歌单中的顺序
+            (MoveSongUp | MoveSongDown | MoveSongTo(_), PlaylistOutside | PlaylistInside) => match actions::current_songlist().await {
+                Some(mut songlist) => move_songlist_song(&cmd, &mut songlist, &mut self.playlist_panel).await?,
+                None => return Err(anyhow!("当前播放列表不是用户歌单")),
+            },
+            //
             (_, _) => return Ok(false),
         }
 
@@ -0,0 +1,288 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel, SonglistsPanel};
+use crate::ui::screen::back_to_main_screen;
+use crate::ui::Controller;
+use crate::{command_queue, ncm_client, player};
+use log::error;
+use ncm_api::model::{DjRadio, Program, Song, Songlist};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Style;
+use ratatui::Frame;
+
+/// 每页获取的节目数量
+const PROGRAMS_PAGE_SIZE: usize = 50;
+
+#[derive(PartialEq)]
+enum Panels {
+    DjRadios,
+    Programs,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    DjRadiosOutside,
+    DjRadiosInside,
+    ProgramsOutside,
+    ProgramsInside,
+}
+
+pub struct PodcastScreen<'a> {
+    current_focus_panel: FocusPanel,
+    //
+    is_loaded: bool,
+    dj_radios: Vec<DjRadio>,
+    current_dj_radio: Option<DjRadio>,
+    programs: Vec<Program>,
+    programs_has_more: bool,
+    //
+    dj_radios_panel: SonglistsPanel<'a>,
+    programs_panel: PlaylistPanel<'a>,
+}
+
+impl<'a> PodcastScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::DjRadiosOutside,
+            is_loaded: false,
+            dj_radios: Vec::new(),
+            current_dj_radio: None,
+            programs: Vec::new(),
+            programs_has_more: false,
+            dj_radios_panel: SonglistsPanel::new(PanelFocusedStatus::Outside),
+            programs_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
+        }
+    }
+
+    /// 节目列表中所选的节目（作为歌曲）
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::ProgramsOutside | FocusPanel::ProgramsInside => self.programs_panel.get_selected_song(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Controller for PodcastScreen<'a> {
+    async fn update_model(&mut self) -> anyhow::Result<bool> {
+        let mut result = Ok(false);
+
+        // 首次进入时获取订阅的电台
+        if !self.is_loaded {
+            self.is_loaded = true;
+            self.load_dj_radios().await;
+            result = Ok(true);
+        }
+
+        if self.dj_radios_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        if self.programs_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            //
+            (Esc, DjRadiosInside) => {
+                self.focus_panel_outside(Panels::DjRadios);
+            },
+            (Esc, ProgramsInside) => {
+                self.focus_panel_outside(Panels::Programs);
+            },
+
+            //
+            (Down | Up, DjRadiosOutside) => {
+                self.focus_panel_inside(Panels::DjRadios);
+            },
+            (Down | Up, ProgramsOutside) => {
+                self.focus_panel_inside(Panels::Programs);
+            },
+            (Down | Up, DjRadiosInside) => {
+                self.dj_radios_panel.handle_event(cmd).await?;
+            },
+            (Down, ProgramsInside) => {
+                // 到达底部时加载下一页节目
+                if self.programs_has_more && self.programs_panel.get_selected_index() == Some(self.programs.len().saturating_sub(1)) {
+                    self.load_next_programs_page().await?;
+                }
+                self.programs_panel.handle_event(cmd).await?;
+            },
+            (Up, ProgramsInside) => {
+                self.programs_panel.handle_event(cmd).await?;
+            },
+
+            //
+            (NextPanel, DjRadiosOutside) => {
+                self.focus_panel_outside(Panels::Programs);
+            },
+            (PrevPanel, ProgramsOutside) => {
+                self.focus_panel_outside(Panels::DjRadios);
+            },
+
+            //
+            (EnterOrPlay, DjRadiosOutside) => {
+                self.focus_panel_inside(Panels::DjRadios);
+            },
+            (EnterOrPlay, ProgramsOutside) => {
+                self.focus_panel_inside(Panels::Programs);
+            },
+            // 打开所选电台，Alt+Enter 时从最新一期开始播放
+            (EnterOrPlay | Play, DjRadiosInside) => {
+                if let Some(dj_radio) = self.dj_radios_panel.get_selected_songlist_index().and_then(|index| self.dj_radios.get(index)).cloned() {
+                    self.current_dj_radio = Some(dj_radio);
+                    self.programs = Vec::new();
+                    self.load_next_programs_page().await?;
+
+                    if matches!(cmd, Play) {
+                        if let Some(dj_radio) = self.current_dj_radio.as_ref() {
+                            player.lock().await.switch_to_podcast(dj_radio, &self.programs);
+                            command_queue.lock().await.push_back(StartPlay);
+
+                            back_to_main_screen().await;
+                        }
+                    } else {
+                        self.focus_panel_inside(Panels::Programs);
+                    }
+                }
+            },
+            // 以电台已加载的节目为播放列表，从选中节目上次的位置开始播放
+            (EnterOrPlay | Play, ProgramsInside) => {
+                if let Some(dj_radio) = self.current_dj_radio.as_ref() {
+                    player.lock().await.switch_to_podcast(dj_radio, &self.programs);
+                    self.programs_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            //
+            (GoToTop | GoToBottom, DjRadiosOutside | DjRadiosInside) => {
+                self.dj_radios_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::DjRadios);
+            },
+            (GoToTop | GoToBottom, ProgramsOutside | ProgramsInside) => {
+                self.programs_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::Programs);
+            },
+
+            //
+            (SearchForward(_) | SearchBackward(_), ProgramsOutside | ProgramsInside) => {
+                self.programs_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::Programs);
+            },
+
+            // 将选中节目加入待播队列
+            (Enqueue, ProgramsOutside | ProgramsInside) => {
+                if let Some(song) = self.programs_panel.get_selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.dj_radios_panel.update_view(style);
+
+        self.programs_panel.update_view(style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+            .split(chunk);
+
+        self.dj_radios_panel.draw(frame, chunks[0]);
+
+        self.programs_panel.draw(frame, chunks[1]);
+    }
+}
+
+/// private
+impl<'a> PodcastScreen<'a> {
+    /// 获取订阅的电台，失败时在面板标题中提示
+    async fn load_dj_radios(&mut self) {
+        let ncm_client_guard = ncm_client.lock().await;
+        if !ncm_client_guard.is_login() {
+            self.dj_radios_panel.set_model("我的电台 (请先登录)", &[]);
+            return;
+        }
+
+        match ncm_client_guard.get_subscribed_djradios().await {
+            Ok(dj_radios) => {
+                self.dj_radios = dj_radios;
+                let songlists: Vec<Songlist> = self.dj_radios.iter().map(|dj_radio| dj_radio.as_songlist(&[])).collect();
+                self.dj_radios_panel.set_model("我的电台", &songlists);
+            },
+            Err(e) => {
+                error!("failed to load subscribed dj radios: {:?}", e);
+                self.dj_radios_panel.set_model(&format!("我的电台 (加载失败: {})", e), &[]);
+            },
+        }
+    }
+
+    /// 获取当前电台的下一页节目，追加到已有节目之后
+    async fn load_next_programs_page(&mut self) -> anyhow::Result<()> {
+        if let Some(dj_radio) = self.current_dj_radio.as_ref() {
+            let is_first_page = self.programs.is_empty();
+            let (programs, has_more) = ncm_client.lock().await.get_djradio_programs(dj_radio.id, self.programs.len(), PROGRAMS_PAGE_SIZE).await?;
+            self.programs.extend(programs);
+            self.programs_has_more = has_more;
+
+            // 防止 set_model 后选中项回到顶部
+            let selected = self.programs_panel.get_selected_index();
+            let songs: Vec<Song> = self.programs.iter().map(Program::as_song).collect();
+            self.programs_panel.set_model(&format!("{} ({}期)", dj_radio.name, dj_radio.programs_count), &songs);
+            if let Some(selected) = selected.filter(|_| !is_first_page) {
+                self.programs_panel.select(selected);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::DjRadios => {
+                self.current_focus_panel = FocusPanel::DjRadiosOutside;
+                self.dj_radios_panel.focused_status = PanelFocusedStatus::Outside;
+                self.programs_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::Programs => {
+                self.current_focus_panel = FocusPanel::ProgramsOutside;
+                self.dj_radios_panel.focused_status = PanelFocusedStatus::Nop;
+                self.programs_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::DjRadios => {
+                self.current_focus_panel = FocusPanel::DjRadiosInside;
+                self.dj_radios_panel.focused_status = PanelFocusedStatus::Inside;
+                self.programs_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::Programs => {
+                self.current_focus_panel = FocusPanel::ProgramsInside;
+                self.dj_radios_panel.focused_status = PanelFocusedStatus::Nop;
+                self.programs_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
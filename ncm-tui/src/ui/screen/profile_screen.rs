@@ -0,0 +1,330 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{ncm_client, player};
+use anyhow::Result;
+use log::error;
+use ncm_api::model::{RankPeriod, Song, Songlist, UserLevel, UserProfile};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Line, Style, Text};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+#[derive(PartialEq)]
+enum Panels {
+    WeeklyRank,
+    AllTimeRank,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    WeeklyRankOutside,
+    WeeklyRankInside,
+    AllTimeRankOutside,
+    AllTimeRankInside,
+}
+
+pub struct ProfileScreen<'a> {
+    // model
+    current_focus_panel: FocusPanel,
+    //
+    is_loaded: bool,
+    profile: Option<UserProfile>,
+    level: Option<UserLevel>,
+    profile_message: String,
+    weekly_rank_songlist: Option<Songlist>,
+    all_time_rank_songlist: Option<Songlist>,
+    //
+    weekly_rank_panel: PlaylistPanel<'a>,
+    all_time_rank_panel: PlaylistPanel<'a>,
+
+    // view
+    profile_page: Paragraph<'a>,
+}
+
+impl<'a> ProfileScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::WeeklyRankOutside,
+            is_loaded: false,
+            profile: None,
+            level: None,
+            profile_message: String::from("加载中..."),
+            weekly_rank_songlist: None,
+            all_time_rank_songlist: None,
+            weekly_rank_panel: PlaylistPanel::new(PanelFocusedStatus::Outside),
+            all_time_rank_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
+            profile_page: Paragraph::default(),
+        }
+    }
+
+    /// 听歌排行中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::WeeklyRankOutside | FocusPanel::WeeklyRankInside => self.weekly_rank_panel.get_selected_song(),
+            FocusPanel::AllTimeRankOutside | FocusPanel::AllTimeRankInside => self.all_time_rank_panel.get_selected_song(),
+        }
+    }
+}
+
+impl<'a> Controller for ProfileScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        let mut result = Ok(false);
+
+        // 首次进入时获取用户详情和听歌排行（离开界面时会重建，再次进入即刷新）
+        if !self.is_loaded {
+            self.is_loaded = true;
+            self.load_profile().await;
+            result = Ok(true);
+        }
+
+        if self.weekly_rank_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        if self.all_time_rank_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            //
+            (Esc, WeeklyRankInside) => {
+                self.focus_panel_outside(Panels::WeeklyRank);
+            },
+            (Esc, AllTimeRankInside) => {
+                self.focus_panel_outside(Panels::AllTimeRank);
+            },
+
+            //
+            (Down | Up | EnterOrPlay, WeeklyRankOutside) => {
+                self.focus_panel_inside(Panels::WeeklyRank);
+            },
+            (Down | Up | EnterOrPlay, AllTimeRankOutside) => {
+                self.focus_panel_inside(Panels::AllTimeRank);
+            },
+            (Down | Up, WeeklyRankInside) => {
+                self.weekly_rank_panel.handle_event(cmd).await?;
+            },
+            (Down | Up, AllTimeRankInside) => {
+                self.all_time_rank_panel.handle_event(cmd).await?;
+            },
+
+            //
+            (NextPanel, WeeklyRankOutside) => {
+                self.focus_panel_outside(Panels::AllTimeRank);
+            },
+            (PrevPanel, AllTimeRankOutside) => {
+                self.focus_panel_outside(Panels::WeeklyRank);
+            },
+
+            // 以听歌排行为播放列表，播放选中歌曲
+            (EnterOrPlay, WeeklyRankInside) => {
+                if let Some(songlist) = self.weekly_rank_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(songlist);
+                    self.weekly_rank_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+            (EnterOrPlay, AllTimeRankInside) => {
+                if let Some(songlist) = self.all_time_rank_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(songlist);
+                    self.all_time_rank_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+            // 从排行第一的歌曲开始播放
+            (Play, _) => {
+                let songlist = match self.current_focus_panel {
+                    WeeklyRankOutside | WeeklyRankInside => self.weekly_rank_songlist.as_ref(),
+                    AllTimeRankOutside | AllTimeRankInside => self.all_time_rank_songlist.as_ref(),
+                };
+
+                if let Some(songlist) = songlist.filter(|songlist| !songlist.songs.is_empty()) {
+                    let mut player_guard = player.lock().await;
+                    player_guard.switch_to_songlist(songlist);
+                    player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                    drop(player_guard);
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            //
+            (GoToTop | GoToBottom | SearchForward(_) | SearchBackward(_), WeeklyRankOutside | WeeklyRankInside) => {
+                self.weekly_rank_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::WeeklyRank);
+            },
+            (GoToTop | GoToBottom | SearchForward(_) | SearchBackward(_), AllTimeRankOutside | AllTimeRankInside) => {
+                self.all_time_rank_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::AllTimeRank);
+            },
+
+            // 将选中歌曲加入待播队列
+            (Enqueue, _) => {
+                if let Some(song) = self.selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), _) => {
+                if let Some(song) = self.selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.weekly_rank_panel.update_view(style);
+
+        self.all_time_rank_panel.update_view(style);
+
+        let profile_text = match self.profile.as_ref() {
+            Some(profile) => {
+                let mut lines = vec![Line::from(format!("\u{1F464}{}", profile.nickname))];
+                if !profile.signature.is_empty() {
+                    lines.push(Line::from(profile.signature.clone()));
+                }
+                lines.push(Line::from(""));
+                lines.push(Line::from(format!("等级: Lv.{}", profile.level)));
+                if let Some(level) = self.level.as_ref() {
+                    lines.push(Line::from(format!("|_ 升级进度: {:.0}%", level.progress * 100.0)));
+                    lines.push(Line::from(format!("|_ 听歌: {} / {}", level.now_play_count, level.next_play_count)));
+                    lines.push(Line::from(format!("|_ 登录天数: {} / {}", level.now_login_count, level.next_login_count)));
+                }
+                lines.push(Line::from(format!("会员: {}", if profile.vip_type > 0 { "是" } else { "否" })));
+                lines.push(Line::from(format!("累计听歌: {}首", profile.listen_songs)));
+                lines.push(Line::from(format!("关注: {}  粉丝: {}", profile.follows, profile.followeds)));
+                lines.push(Line::from(format!("歌单: {}", profile.playlist_count)));
+                lines.push(Line::from(format!("注册天数: {}", profile.create_days)));
+
+                Text::from(lines)
+            },
+            None => Text::from(Line::from(self.profile_message.clone()).centered()),
+        };
+
+        self.profile_page = Paragraph::new(profile_text)
+            .block(Block::default().title("我的").title_bottom(Line::from("按下`Alt+Enter`播放整个排行").centered()).borders(Borders::ALL))
+            .wrap(Wrap { trim: false })
+            .style(*style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+            .split(chunk);
+
+        // 在左侧渲染用户详情
+        frame.render_widget(&self.profile_page, chunks[0]);
+
+        // 在右侧上下渲染两个听歌排行
+        let rank_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(chunks[1]);
+
+        self.weekly_rank_panel.draw(frame, rank_chunks[0]);
+
+        self.all_time_rank_panel.draw(frame, rank_chunks[1]);
+    }
+}
+
+/// private
+impl<'a> ProfileScreen<'a> {
+    /// 获取登录账号的详情、等级和听歌排行，失败时在页面或面板标题中提示
+    async fn load_profile(&mut self) {
+        let ncm_client_guard = ncm_client.lock().await;
+        let login_account = match ncm_client_guard.login_account() {
+            Some(login_account) => login_account,
+            None => {
+                self.profile_message = String::from("请先登录");
+                return;
+            },
+        };
+
+        let profile = match ncm_client_guard.get_user_detail(login_account.user_id).await {
+            Ok(profile) => profile,
+            Err(e) => {
+                error!("failed to load user detail: {:?}", e);
+                self.profile_message = format!("加载失败: {}", e);
+                return;
+            },
+        };
+
+        match ncm_client_guard.get_user_level().await {
+            Ok(level) => self.level = Some(level),
+            Err(e) => error!("failed to load user level: {:?}", e),
+        }
+
+        for period in [RankPeriod::Weekly, RankPeriod::AllTime] {
+            let (rank_panel, rank_songlist) = match period {
+                RankPeriod::Weekly => (&mut self.weekly_rank_panel, &mut self.weekly_rank_songlist),
+                RankPeriod::AllTime => (&mut self.all_time_rank_panel, &mut self.all_time_rank_songlist),
+            };
+
+            match ncm_client_guard.get_user_play_rank(profile.user_id, period).await {
+                Ok(ranked_songs) => {
+                    let songlist = profile.rank_songlist(period, &ranked_songs);
+                    rank_panel.set_model(&songlist.name, &songlist.songs);
+                    *rank_songlist = Some(songlist);
+                },
+                Err(e) => {
+                    error!("failed to load play rank: {:?}", e);
+                    rank_panel.set_model(&format!("{}最常听 (加载失败: {})", period.name(), e), &Vec::new());
+                },
+            }
+        }
+
+        self.profile = Some(profile);
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::WeeklyRank => {
+                self.current_focus_panel = FocusPanel::WeeklyRankOutside;
+                self.weekly_rank_panel.focused_status = PanelFocusedStatus::Outside;
+                self.all_time_rank_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::AllTimeRank => {
+                self.current_focus_panel = FocusPanel::AllTimeRankOutside;
+                self.weekly_rank_panel.focused_status = PanelFocusedStatus::Nop;
+                self.all_time_rank_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::WeeklyRank => {
+                self.current_focus_panel = FocusPanel::WeeklyRankInside;
+                self.weekly_rank_panel.focused_status = PanelFocusedStatus::Inside;
+                self.all_time_rank_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::AllTimeRank => {
+                self.current_focus_panel = FocusPanel::AllTimeRankInside;
+                self.weekly_rank_panel.focused_status = PanelFocusedStatus::Nop;
+                self.all_time_rank_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel, SonglistsPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{command_queue, ncm_client, player};
+use log::error;
+use ncm_api::model::{Song, Songlist};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Style;
+use ratatui::Frame;
+
+#[derive(PartialEq)]
+enum Panels {
+    RecommendSonglists,
+    SonglistContent,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    RecommendSonglistsOutside,
+    RecommendSonglistsInside,
+    SonglistContentOutside,
+    SonglistContentInside,
+}
+
+pub struct RecommendScreen<'a> {
+    current_focus_panel: FocusPanel,
+    //
+    is_loaded: bool,
+    /// 每日推荐歌曲（虚拟歌单）在前，其后为推荐歌单
+    songlists: Vec<Songlist>,
+    current_selected_songlist: Option<Songlist>,
+    //
+    recommend_songlists_panel: SonglistsPanel<'a>,
+    songlist_content_panel: PlaylistPanel<'a>,
+}
+
+impl<'a> RecommendScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::RecommendSonglistsOutside,
+            is_loaded: false,
+            songlists: Vec::new(),
+            current_selected_songlist: None,
+            recommend_songlists_panel: SonglistsPanel::new(PanelFocusedStatus::Outside),
+            songlist_content_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
+        }
+    }
+
+    /// 推荐歌单列表中所选的歌单，或已打开的歌单
+    pub fn selected_songlist(&self) -> Option<Songlist> {
+        match self.current_focus_panel {
+            FocusPanel::RecommendSonglistsOutside | FocusPanel::RecommendSonglistsInside => self.recommend_songlists_panel.get_selected_songlist(),
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.current_selected_songlist.clone(),
+        }
+    }
+
+    /// 歌单内容中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.songlist_content_panel.get_selected_song(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Controller for RecommendScreen<'a> {
+    async fn update_model(&mut self) -> anyhow::Result<bool> {
+        let mut result = Ok(false);
+
+        // 首次进入时获取推荐内容
+        if !self.is_loaded {
+            self.is_loaded = true;
+            self.load_recommendations().await;
+            result = Ok(true);
+        }
+
+        if self.recommend_songlists_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        if self.songlist_content_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            //
+            (Esc, RecommendSonglistsInside) => {
+                self.focus_panel_outside(Panels::RecommendSonglists);
+            },
+            (Esc, SonglistContentInside) => {
+                self.focus_panel_outside(Panels::SonglistContent);
+            },
+
+            //
+            (Down | Up, RecommendSonglistsOutside) => {
+                self.focus_panel_inside(Panels::RecommendSonglists);
+            },
+            (Down | Up, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            (Down | Up, RecommendSonglistsInside) => {
+                self.recommend_songlists_panel.handle_event(cmd).await?;
+            },
+            (Down | Up, SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+            },
+
+            //
+            (NextPanel, RecommendSonglistsOutside) => {
+                self.focus_panel_outside(Panels::SonglistContent);
+            },
+            (PrevPanel, SonglistContentOutside) => {
+                self.focus_panel_outside(Panels::RecommendSonglists);
+            },
+
+            //
+            (EnterOrPlay, RecommendSonglistsOutside) => {
+                self.focus_panel_inside(Panels::RecommendSonglists);
+            },
+            (EnterOrPlay, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            // 打开所选歌单，Alt+Enter 时直接开始播放
+            (EnterOrPlay | Play, RecommendSonglistsInside) => {
+                if let Some(mut selected_songlist) = self.recommend_songlists_panel.get_selected_songlist() {
+                    // 每日推荐歌曲已装载，推荐歌单需要加载
+                    if selected_songlist.songs.is_empty() {
+                        ncm_client.lock().await.load_songlist_songs(&mut selected_songlist).await?;
+                    }
+                    self.songlist_content_panel.set_model(&selected_songlist.name, &selected_songlist.songs);
+
+                    if matches!(cmd, Play) {
+                        player.lock().await.switch_to_songlist(&selected_songlist);
+                        command_queue.lock().await.push_back(StartPlay);
+
+                        back_to_main_screen().await;
+                    } else {
+                        self.focus_panel_inside(Panels::SonglistContent);
+                    }
+
+                    self.current_selected_songlist = Some(selected_songlist);
+                }
+            },
+            // 切换歌单并从选中歌曲开始播放
+            (EnterOrPlay | Play, SonglistContentInside) => {
+                if let Some(selected_songlist) = self.current_selected_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(selected_songlist);
+                    self.songlist_content_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            //
+            (GoToTop | GoToBottom, RecommendSonglistsOutside | RecommendSonglistsInside) => {
+                self.recommend_songlists_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::RecommendSonglists);
+            },
+            (GoToTop | GoToBottom, SonglistContentOutside | SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+
+            //
+            (SearchForward(_) | SearchBackward(_), SonglistContentOutside | SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            // 将选中歌曲加入待播队列
+            (Enqueue, SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.recommend_songlists_panel.update_view(style);
+
+        self.songlist_content_panel.update_view(style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+            .split(chunk);
+
+        self.recommend_songlists_panel.draw(frame, chunks[0]);
+
+        self.songlist_content_panel.draw(frame, chunks[1]);
+    }
+}
+
+/// private
+impl<'a> RecommendScreen<'a> {
+    /// 获取每日推荐歌曲和推荐歌单，失败时在面板标题中提示
+    async fn load_recommendations(&mut self) {
+        let ncm_client_guard = ncm_client.lock().await;
+        if !ncm_client_guard.is_login() {
+            self.recommend_songlists_panel.set_model("每日推荐 (请先登录)", &self.songlists);
+            return;
+        }
+        let daily_songlist = ncm_client_guard.get_daily_songlist().await;
+        let recommend_songlists = ncm_client_guard.get_recommend_songlists().await;
+        drop(ncm_client_guard);
+
+        let mut errors = Vec::new();
+        match daily_songlist {
+            Ok(daily_songlist) => {
+                // 默认显示每日推荐歌曲
+                self.songlist_content_panel.set_model(&daily_songlist.name, &daily_songlist.songs);
+                self.current_selected_songlist = Some(daily_songlist.clone());
+                self.songlists.push(daily_songlist);
+            },
+            Err(e) => {
+                error!("failed to load daily songs: {:?}", e);
+                errors.push(e.to_string());
+            },
+        }
+        match recommend_songlists {
+            Ok(recommend_songlists) => self.songlists.extend(recommend_songlists),
+            Err(e) => {
+                error!("failed to load recommend songlists: {:?}", e);
+                errors.push(e.to_string());
+            },
+        }
+
+        let title = if errors.is_empty() {
+            String::from("每日推荐")
+        } else {
+            format!("每日推荐 (加载失败: {})", errors.join("; "))
+        };
+        self.recommend_songlists_panel.set_model(&title, &self.songlists);
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::RecommendSonglists => {
+                self.current_focus_panel = FocusPanel::RecommendSonglistsOutside;
+                self.recommend_songlists_panel.focused_status = PanelFocusedStatus::Outside;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentOutside;
+                self.recommend_songlists_panel.focused_status = PanelFocusedStatus::Nop;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::RecommendSonglists => {
+                self.current_focus_panel = FocusPanel::RecommendSonglistsInside;
+                self.recommend_songlists_panel.focused_status = PanelFocusedStatus::Inside;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentInside;
+                self.recommend_songlists_panel.focused_status = PanelFocusedStatus::Nop;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
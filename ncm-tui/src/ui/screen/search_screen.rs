@@ -0,0 +1,528 @@
+use crate::config::style::*;
+use crate::config::Command;
+use crate::ui::panel::{AlbumsPanel, ArtistsPanel, PanelFocusedStatus, PlaylistPanel, SonglistsPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{command_queue, ncm_client, player};
+use anyhow::Result;
+use ncm_api::model::{Album, Artist, SearchItems, SearchType, Song, Songlist, SonglistSource};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Style;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Tabs};
+use ratatui::Frame;
+use std::collections::HashMap;
+
+/// 每次搜索请求获取的结果数
+const SEARCH_PAGE_SIZE: usize = 30;
+
+/// tab 顺序
+const SEARCH_TABS: [SearchType; 4] = [SearchType::Song, SearchType::Album, SearchType::Artist, SearchType::Songlist];
+
+#[derive(PartialEq)]
+enum Panels {
+    SearchResults,
+    SonglistContent,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    SearchResultsOutside,
+    SearchResultsInside,
+    SonglistContentOutside,
+    SonglistContentInside,
+}
+
+pub struct SearchScreen<'a> {
+    current_focus_panel: FocusPanel,
+    current_tab: usize,
+    //
+    keywords: String,
+    totals: HashMap<SearchType, usize>, // 各类型的结果总数，已搜索过的类型才有记录
+    songs: Vec<Song>,
+    albums: Vec<Album>,
+    artists: Vec<Artist>,
+    songlists: Vec<Songlist>,
+    current_selected_songlist: Option<Songlist>,
+    //
+    songs_panel: PlaylistPanel<'a>,
+    albums_panel: AlbumsPanel<'a>,
+    artists_panel: ArtistsPanel<'a>,
+    songlists_panel: SonglistsPanel<'a>,
+    songlist_content_panel: PlaylistPanel<'a>,
+
+    // view
+    tabs: Tabs<'a>,
+}
+
+impl<'a> SearchScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::SearchResultsOutside,
+            current_tab: 0,
+            keywords: String::new(),
+            totals: HashMap::new(),
+            songs: Vec::new(),
+            albums: Vec::new(),
+            artists: Vec::new(),
+            songlists: Vec::new(),
+            current_selected_songlist: None,
+            songs_panel: PlaylistPanel::new(PanelFocusedStatus::Outside),
+            albums_panel: AlbumsPanel::new(PanelFocusedStatus::Outside),
+            artists_panel: ArtistsPanel::new(PanelFocusedStatus::Outside),
+            songlists_panel: SonglistsPanel::new(PanelFocusedStatus::Outside),
+            songlist_content_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
+            tabs: Tabs::default(),
+        }
+    }
+
+    /// 单曲搜索结果或歌单内容中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::SearchResultsOutside | FocusPanel::SearchResultsInside if self.current_search_type() == SearchType::Song => self.songs_panel.get_selected_song(),
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.songlist_content_panel.get_selected_song(),
+            _ => None,
+        }
+    }
+
+    /// 歌单搜索结果中所选的歌单，或已打开的歌单
+    pub fn selected_songlist(&self) -> Option<Songlist> {
+        match self.current_focus_panel {
+            FocusPanel::SearchResultsOutside | FocusPanel::SearchResultsInside if self.current_search_type() == SearchType::Songlist => self.songlists_panel.get_selected_songlist(),
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.current_selected_songlist.clone(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Controller for SearchScreen<'a> {
+    async fn update_model(&mut self) -> Result<bool> {
+        let mut result = Ok(false);
+
+        // search results
+        let search_results_changed = match self.current_search_type() {
+            SearchType::Song => self.songs_panel.update_model().await?,
+            SearchType::Album => self.albums_panel.update_model().await?,
+            SearchType::Artist => self.artists_panel.update_model().await?,
+            SearchType::Songlist => self.songlists_panel.update_model().await?,
+        };
+        if search_results_changed {
+            result = Ok(true);
+        }
+
+        // songlist content
+        if self.songlist_content_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            // 新的搜索
+            (GlobalSearch(keywords), _) => {
+                self.start_new_search(keywords.join(" ")).await?;
+            },
+
+            //
+            (Esc, SearchResultsInside) => {
+                self.focus_panel_outside(Panels::SearchResults);
+            },
+            (Esc, SonglistContentInside) => {
+                self.focus_panel_outside(Panels::SonglistContent);
+            },
+
+            //
+            (Down | Up, SearchResultsOutside) => {
+                self.focus_panel_inside(Panels::SearchResults);
+            },
+            (Down | Up, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            (Down, SearchResultsInside) => {
+                // 到达底部时加载下一页
+                if self.is_last_result_selected() && self.has_more_results() {
+                    self.search_next_page().await?;
+                }
+                self.current_results_panel_handle_event(cmd).await?;
+            },
+            (Up, SearchResultsInside) => {
+                self.current_results_panel_handle_event(cmd).await?;
+            },
+            (Down | Up, SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+            },
+
+            // 左右切换 tab ，最后一个 tab（歌单）右侧为歌单内容
+            (NextPanel, SearchResultsOutside | SearchResultsInside) => {
+                if self.current_tab + 1 < SEARCH_TABS.len() {
+                    self.switch_tab(self.current_tab + 1).await?;
+                } else if self.current_selected_songlist.is_some() {
+                    self.focus_panel_outside(Panels::SonglistContent);
+                }
+            },
+            (PrevPanel, SearchResultsOutside | SearchResultsInside) => {
+                if self.current_tab > 0 {
+                    self.switch_tab(self.current_tab - 1).await?;
+                }
+            },
+            (PrevPanel, SonglistContentOutside | SonglistContentInside) => {
+                self.focus_panel_outside(Panels::SearchResults);
+            },
+
+            //
+            (EnterOrPlay, SearchResultsOutside) => {
+                self.focus_panel_inside(Panels::SearchResults);
+            },
+            (EnterOrPlay, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            (EnterOrPlay | Play, SearchResultsInside) => match self.current_search_type() {
+                // 以搜索到的歌曲为播放列表，播放选中歌曲
+                SearchType::Song => {
+                    if self.songs_panel.get_selected_index().is_some() {
+                        player.lock().await.switch_to_songlist(&self.songs_as_songlist());
+                        self.songs_panel.handle_event(cmd).await?;
+
+                        back_to_main_screen().await;
+                    }
+                },
+                SearchType::Album => {
+                    if let Some(selected_album) = self.albums_panel.get_selected_album() {
+                        if matches!(cmd, Play) {
+                            // 从第一首开始播放整张专辑
+                            let album = ncm_client.lock().await.get_album(selected_album.id).await?;
+                            let mut player_guard = player.lock().await;
+                            player_guard.switch_to_songlist(&album.as_songlist());
+                            player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                            drop(player_guard);
+
+                            back_to_main_screen().await;
+                        } else {
+                            // 进入专辑页面
+                            command_queue.lock().await.push_back(OpenAlbum(selected_album.id));
+                        }
+                    }
+                },
+                SearchType::Artist => {
+                    if let Some(mut selected_artist) = self.artists_panel.get_selected_artist() {
+                        if matches!(cmd, Play) {
+                            // 播放歌手热门歌曲
+                            selected_artist.top_songs = ncm_client.lock().await.get_artist_top_songs(selected_artist.id).await?;
+                            let mut player_guard = player.lock().await;
+                            player_guard.switch_to_songlist(&selected_artist.as_songlist());
+                            player_guard.play_particularly_now(0, ncm_client.lock().await).await?;
+                            drop(player_guard);
+
+                            back_to_main_screen().await;
+                        } else {
+                            // 进入歌手页面
+                            command_queue.lock().await.push_back(OpenArtist(selected_artist.id));
+                        }
+                    }
+                },
+                SearchType::Songlist => {
+                    if let Some(mut selected_songlist) = self.songlists_panel.get_selected_songlist() {
+                        // 加载歌单
+                        ncm_client.lock().await.load_songlist_songs(&mut selected_songlist).await?;
+                        self.songlist_content_panel.set_model(&selected_songlist.name, &selected_songlist.songs);
+
+                        if matches!(cmd, Play) {
+                            // 切换歌单并开始播放
+                            player.lock().await.switch_to_songlist(&selected_songlist);
+                            command_queue.lock().await.push_back(StartPlay);
+
+                            back_to_main_screen().await;
+                        } else {
+                            self.focus_panel_inside(Panels::SonglistContent);
+                        }
+
+                        self.current_selected_songlist = Some(selected_songlist);
+                    }
+                },
+            },
+            // 切换歌单并从选中歌曲开始播放
+            (EnterOrPlay | Play, SonglistContentInside) => {
+                if let Some(selected_songlist) = self.current_selected_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(selected_songlist);
+                    self.songlist_content_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            //
+            (GoToTop | GoToBottom, SearchResultsOutside | SearchResultsInside) => {
+                self.current_results_panel_handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SearchResults);
+            },
+            (GoToTop | GoToBottom, SonglistContentOutside | SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), SearchResultsOutside | SearchResultsInside) if self.current_search_type() == SearchType::Song => {
+                if let Some(song) = self.songs_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+            (ShowAlbum | ShowArtist(_), SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.tabs = Tabs::new(
+            SEARCH_TABS
+                .iter()
+                .map(|search_type| match self.totals.get(search_type) {
+                    Some(total) => format!("{}({})", search_type_name(search_type), total),
+                    None => search_type_name(search_type).to_string(),
+                })
+                .collect::<Vec<String>>(),
+        )
+        .block(Block::default().title(Line::from(format!("搜索: {}", self.keywords))).borders(Borders::ALL))
+        .highlight_style(ITEM_SELECTED_STYLE)
+        .select(self.current_tab)
+        .style(*style);
+
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.update_view(style),
+            SearchType::Album => self.albums_panel.update_view(style),
+            SearchType::Artist => self.artists_panel.update_view(style),
+            SearchType::Songlist => self.songlists_panel.update_view(style),
+        }
+
+        self.songlist_content_panel.update_view(style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(chunk);
+
+        // 左半屏上方渲染 tabs ，下方渲染当前 tab 对应的搜索结果
+        let left_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)].as_ref())
+            .split(chunks[0]);
+
+        frame.render_widget(&self.tabs, left_chunks[0]);
+
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.draw(frame, left_chunks[1]),
+            SearchType::Album => self.albums_panel.draw(frame, left_chunks[1]),
+            SearchType::Artist => self.artists_panel.draw(frame, left_chunks[1]),
+            SearchType::Songlist => self.songlists_panel.draw(frame, left_chunks[1]),
+        }
+
+        // 在右半屏渲染 songlist_content_panel
+        self.songlist_content_panel.draw(frame, chunks[1]);
+    }
+}
+
+/// private
+impl<'a> SearchScreen<'a> {
+    fn current_search_type(&self) -> SearchType {
+        SEARCH_TABS[self.current_tab]
+    }
+
+    /// 清空上一次搜索的结果，搜索新的关键词
+    async fn start_new_search(&mut self, keywords: String) -> Result<()> {
+        self.keywords = keywords;
+        self.totals = HashMap::new();
+        self.songs = Vec::new();
+        self.albums = Vec::new();
+        self.artists = Vec::new();
+        self.songlists = Vec::new();
+        self.current_selected_songlist = None;
+        self.songlist_content_panel = PlaylistPanel::new(PanelFocusedStatus::Nop);
+
+        self.current_tab = 0;
+        self.search_next_page().await?;
+        self.focus_panel_inside(Panels::SearchResults);
+
+        Ok(())
+    }
+
+    /// 切换 tab ，未搜索过的类型在切换时搜索
+    async fn switch_tab(&mut self, to_tab: usize) -> Result<()> {
+        self.current_tab = to_tab;
+
+        if !self.keywords.is_empty() && !self.totals.contains_key(&self.current_search_type()) {
+            self.search_next_page().await?;
+        }
+
+        self.focus_panel_outside(Panels::SearchResults);
+
+        Ok(())
+    }
+
+    /// 获取当前类型的下一页结果，追加到已有结果之后
+    async fn search_next_page(&mut self) -> Result<()> {
+        let search_type = self.current_search_type();
+        let offset = self.loaded_results_count();
+
+        let search_result = ncm_client.lock().await.search(&self.keywords, search_type, offset, SEARCH_PAGE_SIZE).await?;
+        self.totals.insert(search_type, search_result.total);
+
+        // 防止 set_model 后选中项回到顶部
+        let selected = self.current_results_selected_index();
+        let title = format!("{}: {}", search_type_name(&search_type), self.keywords);
+
+        match search_result.items {
+            SearchItems::Songs(songs) => {
+                self.songs.extend(songs);
+                self.songs_panel.set_model(&title, &self.songs);
+            },
+            SearchItems::Albums(albums) => {
+                self.albums.extend(albums);
+                self.albums_panel.set_model(&title, &self.albums);
+            },
+            SearchItems::Artists(artists) => {
+                self.artists.extend(artists);
+                self.artists_panel.set_model(&title, &self.artists);
+            },
+            SearchItems::Songlists(songlists) => {
+                self.songlists.extend(songlists);
+                self.songlists_panel.set_model(&title, &self.songlists);
+            },
+        }
+
+        if let Some(selected) = selected {
+            self.current_results_select(selected);
+        }
+
+        Ok(())
+    }
+
+    fn loaded_results_count(&self) -> usize {
+        match self.current_search_type() {
+            SearchType::Song => self.songs.len(),
+            SearchType::Album => self.albums.len(),
+            SearchType::Artist => self.artists.len(),
+            SearchType::Songlist => self.songlists.len(),
+        }
+    }
+
+    fn has_more_results(&self) -> bool {
+        match self.totals.get(&self.current_search_type()) {
+            Some(total) => self.loaded_results_count() < *total,
+            None => false,
+        }
+    }
+
+    fn is_last_result_selected(&self) -> bool {
+        match self.current_results_selected_index() {
+            Some(selected) => selected + 1 >= self.loaded_results_count(),
+            None => false,
+        }
+    }
+
+    fn current_results_selected_index(&self) -> Option<usize> {
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.get_selected_index(),
+            SearchType::Album => self.albums_panel.get_selected_index(),
+            SearchType::Artist => self.artists_panel.get_selected_index(),
+            SearchType::Songlist => self.songlists_panel.get_selected_songlist_index(),
+        }
+    }
+
+    fn current_results_select(&mut self, index: usize) {
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.select(index),
+            SearchType::Album => self.albums_panel.select(index),
+            SearchType::Artist => self.artists_panel.select(index),
+            SearchType::Songlist => self.songlists_panel.select(index),
+        }
+    }
+
+    async fn current_results_panel_handle_event(&mut self, cmd: Command) -> Result<bool> {
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.handle_event(cmd).await,
+            SearchType::Album => self.albums_panel.handle_event(cmd).await,
+            SearchType::Artist => self.artists_panel.handle_event(cmd).await,
+            SearchType::Songlist => self.songlists_panel.handle_event(cmd).await,
+        }
+    }
+
+    /// 搜索到的歌曲组成的临时歌单
+    fn songs_as_songlist(&self) -> Songlist {
+        Songlist {
+            name: format!("搜索: {}", self.keywords),
+            id: 0,
+            songs_count: self.songs.len(),
+            creator: String::new(),
+            creator_id: 0,
+            subscribed: false,
+            songs: self.songs.clone(),
+            source: SonglistSource::Search,
+        }
+    }
+
+    fn set_results_focused_status(&mut self, focused_status: PanelFocusedStatus) {
+        match self.current_search_type() {
+            SearchType::Song => self.songs_panel.focused_status = focused_status,
+            SearchType::Album => self.albums_panel.focused_status = focused_status,
+            SearchType::Artist => self.artists_panel.focused_status = focused_status,
+            SearchType::Songlist => self.songlists_panel.focused_status = focused_status,
+        }
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::SearchResults => {
+                self.current_focus_panel = FocusPanel::SearchResultsOutside;
+                self.set_results_focused_status(PanelFocusedStatus::Outside);
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentOutside;
+                self.set_results_focused_status(PanelFocusedStatus::Nop);
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::SearchResults => {
+                self.current_focus_panel = FocusPanel::SearchResultsInside;
+                self.set_results_focused_status(PanelFocusedStatus::Inside);
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentInside;
+                self.set_results_focused_status(PanelFocusedStatus::Nop);
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
+
+#[inline]
+fn search_type_name(search_type: &SearchType) -> &'static str {
+    match search_type {
+        SearchType::Song => "单曲",
+        SearchType::Album => "专辑",
+        SearchType::Artist => "歌手",
+        SearchType::Songlist => "歌单",
+    }
+}
@@ -1,12 +1,17 @@
 use crate::config::{Command, ScreenEnum};
 use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel, SonglistsPanel};
+use crate::ui::screen::{move_songlist_song, show_album_or_artist_of};
 use crate::ui::Controller;
 use crate::{command_queue, ncm_client, player};
-use log::debug;
-use ncm_api::model::Songlist;
+use log::{debug, error};
+use ncm_api::model::{Song, Songlist};
+use ncm_api::NcmResult;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::Style;
 use ratatui::Frame;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::task::{self, JoinHandle};
 
 #[derive(PartialEq)]
 enum Panels {
@@ -22,10 +27,25 @@ enum FocusPanel {
     SonglistContentInside,
 }
 
+/// 正在后台加载歌曲的歌单
+struct LoadingSonglist {
+    songlist: Songlist,
+    /// 已获取页数
+    loaded_pages: Arc<AtomicUsize>,
+    /// 总页数
+    total_pages: Arc<AtomicUsize>,
+    /// 上次显示的进度（已获取页数）
+    shown_loaded_pages: Option<usize>,
+    /// 加载完成后是否切换到该歌单并开始播放
+    play_when_loaded: bool,
+    handle: JoinHandle<NcmResult<Vec<Song>>>,
+}
+
 pub struct SonglistsScreen<'a> {
     current_focus_panel: FocusPanel,
     //
     current_selected_songlist: Option<Songlist>,
+    loading_songlist: Option<LoadingSonglist>,
     //
     songlist_candidates_panel: SonglistsPanel<'a>,
     songlist_content_panel: PlaylistPanel<'a>,
@@ -36,10 +56,31 @@ impl<'a> SonglistsScreen<'a> {
         Self {
             current_focus_panel: FocusPanel::SonglistCandidatesOutside,
             current_selected_songlist: None,
+            loading_songlist: None,
             songlist_candidates_panel: SonglistsPanel::new(PanelFocusedStatus::Outside),
             songlist_content_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
         }
     }
+
+    /// 歌单列表中所选的歌单
+    pub fn selected_songlist(&self) -> Option<Songlist> {
+        self.songlist_candidates_panel.get_selected_songlist()
+    }
+
+    /// 歌单内容中所选的歌曲及其所在的歌单
+    pub fn selected_song(&self) -> Option<(Songlist, Song)> {
+        match self.current_focus_panel {
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => Some((self.current_selected_songlist.clone()?, self.songlist_content_panel.get_selected_song()?)),
+            _ => None,
+        }
+    }
+
+    /// 重新加载正在显示的歌单内容（歌单内的歌曲有变化后调用）
+    pub async fn reload_current_songlist(&mut self) {
+        if let Some(songlist) = self.current_selected_songlist.take() {
+            self.start_loading_songlist(songlist, false).await;
+        }
+    }
 }
 
 impl<'a> Controller for SonglistsScreen<'a> {
@@ -51,6 +92,11 @@ impl<'a> Controller for SonglistsScreen<'a> {
             result = Ok(true);
         }
 
+        // 后台加载的歌单
+        if self.update_loading_songlist().await {
+            result = Ok(true);
+        }
+
         // songlist content
         if self.songlist_content_panel.update_model().await? {
             result = Ok(true);
@@ -102,22 +148,18 @@ impl<'a> Controller for SonglistsScreen<'a> {
                 self.focus_panel_inside(Panels::SonglistContent);
             },
             (EnterOrPlay, SonglistCandidatesInside) => {
-                // 加载歌单
-                if let Some(mut selected_songlist) = self.songlist_candidates_panel.get_selected_songlist() {
-                    ncm_client.lock().await.load_songlist_songs(&mut selected_songlist).await?;
-
-                    self.songlist_content_panel.set_model(&selected_songlist.name, &selected_songlist.songs);
-
-                    self.current_selected_songlist = Some(selected_songlist);
+                // 在后台加载歌单，加载期间显示进度
+                if let Some(selected_songlist) = self.songlist_candidates_panel.get_selected_songlist() {
+                    self.start_loading_songlist(selected_songlist, false).await;
                 }
             },
-            // 切换歌单并从选中歌曲开始播放
+            // 切换到正在显示的歌单并从选中歌曲开始播放
             (EnterOrPlay | Play, SonglistContentInside) => {
-                if let Some(selected_songlist_index) = self.songlist_candidates_panel.get_selected_songlist_index() {
-                    debug!("切换到 {} 号歌单", selected_songlist_index);
+                if let Some(songlist) = self.current_selected_songlist.as_ref() {
+                    debug!("切换到歌单 {}", songlist.name);
 
-                    // 切换当前播放列表
-                    player.lock().await.switch_playlist(selected_songlist_index, ncm_client.lock().await).await?;
+                    // 切换当前播放列表（歌曲已在后台装载）
+                    player.lock().await.switch_playlist(songlist);
 
                     // 播放选中歌曲
                     self.songlist_content_panel.handle_event(cmd).await?;
@@ -130,21 +172,13 @@ impl<'a> Controller for SonglistsScreen<'a> {
                     drop(command_queue_guard);
                 }
             },
-            // 切换歌单并开始播放
+            // 切换歌单并开始播放，歌单尚未装载时在后台装载完成后再开始播放
             (Play, SonglistCandidatesInside) => {
-                if let Some(selected_songlist_index) = self.songlist_candidates_panel.get_selected_songlist_index() {
-                    debug!("切换到 {} 号歌单", selected_songlist_index);
-
-                    // 切换当前播放列表
-                    player.lock().await.switch_playlist(selected_songlist_index, ncm_client.lock().await).await?;
-
-                    // 开始自动播放，返回 main_screen ，刷新播放列表显示
-                    let mut command_queue_guard = command_queue.lock().await;
-                    command_queue_guard.push_back(StartPlay);
-                    command_queue_guard.push_back(GotoScreen(ScreenEnum::Main));
-                    command_queue_guard.push_back(RefreshPlaylist);
-                    command_queue_guard.push_back(WhereIsThisSong);
-                    drop(command_queue_guard);
+                if let Some(selected_songlist) = self.songlist_candidates_panel.get_selected_songlist() {
+                    match self.current_selected_songlist.clone() {
+                        Some(songlist) if songlist.id == selected_songlist.id => play_songlist(&songlist).await,
+                        _ => self.start_loading_songlist(selected_songlist, true).await,
+                    }
                 }
             },
 
@@ -166,6 +200,20 @@ impl<'a> Controller for SonglistsScreen<'a> {
                 self.focus_panel_inside(Panels::SonglistContent);
             },
 
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            // 调整所选歌曲在歌单中的顺序
+            (MoveSongUp | MoveSongDown | MoveSongTo(_), SonglistContentOutside | SonglistContentInside) => {
+                if let Some(songlist) = self.current_selected_songlist.as_mut() {
+                    move_songlist_song(&cmd, songlist, &mut self.songlist_content_panel).await?;
+                }
+            },
+
             //
             (_, _) => {
                 return Ok(false);
@@ -198,6 +246,74 @@ impl<'a> Controller for SonglistsScreen<'a> {
 
 /// private
 impl<'a> SonglistsScreen<'a> {
+    /// 开始在后台加载歌单内的歌曲（取消仍未完成的上一次加载），`play_when_loaded` 时加载完成后开始播放
+    async fn start_loading_songlist(&mut self, songlist: Songlist, play_when_loaded: bool) {
+        if let Some(loading_songlist) = self.loading_songlist.take() {
+            loading_songlist.handle.abort();
+        }
+
+        let loader = ncm_client.lock().await.songlist_songs_loader(&songlist);
+
+        let loaded_pages = Arc::new(AtomicUsize::new(0));
+        let total_pages = Arc::new(AtomicUsize::new(loader.pages_count()));
+        let (loaded_pages_2, total_pages_2) = (Arc::clone(&loaded_pages), Arc::clone(&total_pages));
+        let handle = task::spawn(loader.load(move |loaded, total| {
+            loaded_pages_2.store(loaded, Ordering::Relaxed);
+            total_pages_2.store(total, Ordering::Relaxed);
+        }));
+
+        self.loading_songlist = Some(LoadingSonglist {
+            songlist,
+            loaded_pages,
+            total_pages,
+            shown_loaded_pages: None,
+            play_when_loaded,
+            handle,
+        });
+    }
+
+    /// 更新后台加载的进度，加载完成后显示歌单内容，返回是否需要重新渲染
+    async fn update_loading_songlist(&mut self) -> bool {
+        let loading_songlist = match self.loading_songlist.as_mut() {
+            Some(loading_songlist) => loading_songlist,
+            None => return false,
+        };
+
+        if !loading_songlist.handle.is_finished() {
+            // 进度有变化时更新加载提示
+            let loaded_pages = loading_songlist.loaded_pages.load(Ordering::Relaxed);
+            if loading_songlist.shown_loaded_pages != Some(loaded_pages) {
+                loading_songlist.shown_loaded_pages = Some(loaded_pages);
+                let total_pages = loading_songlist.total_pages.load(Ordering::Relaxed);
+                self.songlist_content_panel
+                    .set_model(&format!("{} (加载中 {}/{})", loading_songlist.songlist.name, loaded_pages, total_pages), &Vec::new());
+                return true;
+            }
+
+            return false;
+        }
+
+        if let Some(mut loading_songlist) = self.loading_songlist.take() {
+            match (&mut loading_songlist.handle).await {
+                Ok(Ok(songs)) => {
+                    loading_songlist.songlist.songs = songs;
+                    self.songlist_content_panel.set_model(&loading_songlist.songlist.name, &loading_songlist.songlist.songs);
+                    if loading_songlist.play_when_loaded {
+                        play_songlist(&loading_songlist.songlist).await;
+                    }
+                    self.current_selected_songlist = Some(loading_songlist.songlist);
+                },
+                Ok(Err(e)) => {
+                    error!("failed to load songlist {}: {:?}", loading_songlist.songlist.id, e);
+                    self.songlist_content_panel.set_model(&format!("{} (加载失败: {})", loading_songlist.songlist.name, e), &Vec::new());
+                },
+                Err(e) => error!("songlist loading task failed: {:?}", e),
+            }
+        }
+
+        true
+    }
+
     fn focus_panel_outside(&mut self, to_panel: Panels) {
         match to_panel {
             Panels::SonglistCandidates => {
@@ -228,3 +344,17 @@ impl<'a> SonglistsScreen<'a> {
         }
     }
 }
+
+/// 切换到已装载歌曲的歌单并开始自动播放，返回 main_screen ，刷新播放列表显示
+async fn play_songlist(songlist: &Songlist) {
+    debug!("切换到歌单 {}", songlist.name);
+
+    player.lock().await.switch_playlist(songlist);
+
+    let mut command_queue_guard = command_queue.lock().await;
+    command_queue_guard.push_back(Command::StartPlay);
+    command_queue_guard.push_back(Command::GotoScreen(ScreenEnum::Main));
+    command_queue_guard.push_back(Command::RefreshPlaylist);
+    command_queue_guard.push_back(Command::WhereIsThisSong);
+    drop(command_queue_guard);
+}
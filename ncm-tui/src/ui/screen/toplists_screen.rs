@@ -0,0 +1,256 @@
+use crate::config::Command;
+use crate::ui::panel::{PanelFocusedStatus, PlaylistPanel, SonglistsPanel};
+use crate::ui::screen::{back_to_main_screen, show_album_or_artist_of};
+use crate::ui::Controller;
+use crate::{command_queue, ncm_client, player};
+use log::error;
+use ncm_api::model::{Song, Songlist};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Style;
+use ratatui::Frame;
+
+#[derive(PartialEq)]
+enum Panels {
+    Toplists,
+    SonglistContent,
+}
+
+#[derive(PartialEq)]
+enum FocusPanel {
+    ToplistsOutside,
+    ToplistsInside,
+    SonglistContentOutside,
+    SonglistContentInside,
+}
+
+pub struct ToplistsScreen<'a> {
+    current_focus_panel: FocusPanel,
+    //
+    is_loaded: bool,
+    current_selected_songlist: Option<Songlist>,
+    //
+    toplists_panel: SonglistsPanel<'a>,
+    songlist_content_panel: PlaylistPanel<'a>,
+}
+
+impl<'a> ToplistsScreen<'a> {
+    pub fn new(_normal_style: &Style) -> Self {
+        Self {
+            current_focus_panel: FocusPanel::ToplistsOutside,
+            is_loaded: false,
+            current_selected_songlist: None,
+            toplists_panel: SonglistsPanel::new(PanelFocusedStatus::Outside),
+            songlist_content_panel: PlaylistPanel::new(PanelFocusedStatus::Nop),
+        }
+    }
+
+    /// 排行榜列表中所选的排行榜，或已打开的排行榜
+    pub fn selected_songlist(&self) -> Option<Songlist> {
+        match self.current_focus_panel {
+            FocusPanel::ToplistsOutside | FocusPanel::ToplistsInside => self.toplists_panel.get_selected_songlist(),
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.current_selected_songlist.clone(),
+        }
+    }
+
+    /// 歌单内容中所选的歌曲
+    pub fn selected_song(&self) -> Option<Song> {
+        match self.current_focus_panel {
+            FocusPanel::SonglistContentOutside | FocusPanel::SonglistContentInside => self.songlist_content_panel.get_selected_song(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Controller for ToplistsScreen<'a> {
+    async fn update_model(&mut self) -> anyhow::Result<bool> {
+        let mut result = Ok(false);
+
+        // 首次进入时获取排行榜
+        if !self.is_loaded {
+            self.is_loaded = true;
+            self.load_toplists().await;
+            result = Ok(true);
+        }
+
+        if self.toplists_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        if self.songlist_content_panel.update_model().await? {
+            result = Ok(true);
+        }
+
+        result
+    }
+
+    async fn handle_event(&mut self, cmd: Command) -> anyhow::Result<bool> {
+        use Command::*;
+        use FocusPanel::*;
+
+        match (cmd.clone(), &self.current_focus_panel) {
+            //
+            (Esc, ToplistsInside) => {
+                self.focus_panel_outside(Panels::Toplists);
+            },
+            (Esc, SonglistContentInside) => {
+                self.focus_panel_outside(Panels::SonglistContent);
+            },
+
+            //
+            (Down | Up, ToplistsOutside) => {
+                self.focus_panel_inside(Panels::Toplists);
+            },
+            (Down | Up, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            (Down | Up, ToplistsInside) => {
+                self.toplists_panel.handle_event(cmd).await?;
+            },
+            (Down | Up, SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+            },
+
+            //
+            (NextPanel, ToplistsOutside) => {
+                self.focus_panel_outside(Panels::SonglistContent);
+            },
+            (PrevPanel, SonglistContentOutside) => {
+                self.focus_panel_outside(Panels::Toplists);
+            },
+
+            //
+            (EnterOrPlay, ToplistsOutside) => {
+                self.focus_panel_inside(Panels::Toplists);
+            },
+            (EnterOrPlay, SonglistContentOutside) => {
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+            // 打开所选排行榜，Alt+Enter 时直接开始播放
+            (EnterOrPlay | Play, ToplistsInside) => {
+                if let Some(mut selected_songlist) = self.toplists_panel.get_selected_songlist() {
+                    ncm_client.lock().await.load_toplist_songs(&mut selected_songlist).await?;
+                    self.songlist_content_panel.set_model(&selected_songlist.name, &selected_songlist.songs);
+
+                    if matches!(cmd, Play) {
+                        player.lock().await.switch_to_songlist(&selected_songlist);
+                        command_queue.lock().await.push_back(StartPlay);
+
+                        back_to_main_screen().await;
+                    } else {
+                        self.focus_panel_inside(Panels::SonglistContent);
+                    }
+
+                    self.current_selected_songlist = Some(selected_songlist);
+                }
+            },
+            // 以排行榜为播放列表，从选中歌曲开始播放
+            (EnterOrPlay | Play, SonglistContentInside) => {
+                if let Some(selected_songlist) = self.current_selected_songlist.as_ref() {
+                    player.lock().await.switch_to_songlist(selected_songlist);
+                    self.songlist_content_panel.handle_event(cmd).await?;
+
+                    back_to_main_screen().await;
+                }
+            },
+
+            //
+            (GoToTop | GoToBottom, ToplistsOutside | ToplistsInside) => {
+                self.toplists_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::Toplists);
+            },
+            (GoToTop | GoToBottom, SonglistContentOutside | SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+
+            //
+            (SearchForward(_) | SearchBackward(_), SonglistContentOutside | SonglistContentInside) => {
+                self.songlist_content_panel.handle_event(cmd).await?;
+                self.focus_panel_inside(Panels::SonglistContent);
+            },
+
+            // 查看所选歌曲所属专辑/歌手
+            (ShowAlbum | ShowArtist(_), SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    show_album_or_artist_of(&cmd, &song).await?;
+                }
+            },
+
+            // 将选中歌曲加入待播队列
+            (Enqueue, SonglistContentOutside | SonglistContentInside) => {
+                if let Some(song) = self.songlist_content_panel.get_selected_song() {
+                    player.lock().await.enqueue_songs(&[song]);
+                }
+            },
+
+            //
+            (_, _) => {
+                return Ok(false);
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn update_view(&mut self, style: &Style) {
+        self.toplists_panel.update_view(style);
+
+        self.songlist_content_panel.update_view(style);
+    }
+
+    fn draw(&self, frame: &mut Frame, chunk: Rect) {
+        // 分为左右两个面板
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+            .split(chunk);
+
+        self.toplists_panel.draw(frame, chunks[0]);
+
+        self.songlist_content_panel.draw(frame, chunks[1]);
+    }
+}
+
+/// private
+impl<'a> ToplistsScreen<'a> {
+    /// 获取排行榜列表，失败时在面板标题中提示
+    async fn load_toplists(&mut self) {
+        match ncm_client.lock().await.get_toplists().await {
+            Ok(toplists) => self.toplists_panel.set_model("排行榜", &toplists),
+            Err(e) => {
+                error!("failed to load toplists: {:?}", e);
+                self.toplists_panel.set_model(&format!("排行榜 (加载失败: {})", e), &Vec::new());
+            },
+        }
+    }
+
+    fn focus_panel_outside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::Toplists => {
+                self.current_focus_panel = FocusPanel::ToplistsOutside;
+                self.toplists_panel.focused_status = PanelFocusedStatus::Outside;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentOutside;
+                self.toplists_panel.focused_status = PanelFocusedStatus::Nop;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Outside;
+            },
+        }
+    }
+
+    fn focus_panel_inside(&mut self, to_panel: Panels) {
+        match to_panel {
+            Panels::Toplists => {
+                self.current_focus_panel = FocusPanel::ToplistsInside;
+                self.toplists_panel.focused_status = PanelFocusedStatus::Inside;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Nop;
+            },
+            Panels::SonglistContent => {
+                self.current_focus_panel = FocusPanel::SonglistContentInside;
+                self.toplists_panel.focused_status = PanelFocusedStatus::Nop;
+                self.songlist_content_panel.focused_status = PanelFocusedStatus::Inside;
+            },
+        }
+    }
+}
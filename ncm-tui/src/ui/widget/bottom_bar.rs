@@ -1,23 +1,29 @@
 use crate::config::Command;
-use crate::player;
-use crate::ui::Controller;
+use crate::ui::{format_duration, Controller};
+use crate::{ncm_client, player};
 use anyhow::Result;
 use ratatui::layout::{Layout, Rect};
 use ratatui::prelude::{Constraint, Direction, Style};
 use ratatui::style::palette::tailwind;
+use ratatui::style::Stylize;
 use ratatui::text::{Line, Text};
 use ratatui::widgets::{Block, Borders, Gauge, Paragraph};
 use ratatui::Frame;
+use std::collections::HashSet;
 
 pub struct BottomBar<'a> {
     // model
     info_bar_text: Text<'a>,
+    is_heart_mode: bool,
     //
     playback_ratio: f64,
     playback_label: String,
     song_name: Option<String>,
     singer_name: Option<String>,
     song_quality_level: Option<String>,
+    is_song_liked: bool,
+    liked_song_ids: HashSet<u64>, // 用户喜欢的歌曲（ncm_client 缓存的副本）
+    liked_songs_revision: usize,
     //
     volume: f64,
 
@@ -31,11 +37,15 @@ impl<'a> BottomBar<'a> {
     pub fn new(_normal_style: &Style) -> Self {
         Self {
             info_bar_text: Text::default(),
+            is_heart_mode: false,
             playback_ratio: 0.0,
             playback_label: String::new(),
             song_name: None,
             singer_name: None,
             song_quality_level: None,
+            is_song_liked: false,
+            liked_song_ids: HashSet::new(),
+            liked_songs_revision: 0,
             volume: 0.0,
             control_bar: Paragraph::default(),
             playback_bar: Gauge::default(),
@@ -50,6 +60,7 @@ impl<'a> Controller for BottomBar<'a> {
 
         // control_bar
         self.info_bar_text = Text::from(Line::from(format!(" {}  |  {}  ", player_guard.play_mode(), if player_guard.is_playing() { '\u{f03e4}' } else { '\u{f040a}' },)).centered());
+        self.is_heart_mode = player_guard.is_heart_mode();
 
         // playback_bar
         if let (Some(player_position), Some(player_duration)) = (player_guard.position(), player_guard.duration()) {
@@ -58,25 +69,34 @@ impl<'a> Controller for BottomBar<'a> {
             } else {
                 1.0
             };
-            self.playback_label = format!(
-                "{:02}:{:02}/{:02}:{:02}",
-                player_position.minutes(),
-                player_position.seconds() % 60,
-                player_duration.minutes(),
-                player_duration.seconds() % 60,
-            );
+            // 时长超过 1 小时（如电台节目）时带上小时
+            let with_hours = player_duration.hours() > 0;
+            self.playback_label = format!("{}/{}", format_duration(player_position.mseconds(), with_hours), format_duration(player_duration.mseconds(), with_hours));
         } else {
             self.playback_ratio = 0.0;
             self.playback_label = String::from("--:--/--:--");
         };
-        if let Some(song) = player_guard.current_song().clone() {
+        let current_song_id = player_guard.current_song().as_ref().map(|song| song.id);
+        if let Some(song) = player_guard.current_song() {
             self.song_name = Some(song.name.clone());
-            self.singer_name = Some(song.singer.clone());
+            self.singer_name = Some(song.singer());
             self.song_quality_level = Some(song.quality_level.clone());
         }
 
         // volume_bar
         self.volume = player_guard.volume();
+        drop(player_guard);
+
+        // 喜欢的歌曲有变化时更新缓存（ncm_client 正在请求时跳过，下一帧再检查，避免阻塞界面）
+        if let Ok(ncm_client_guard) = ncm_client.try_lock() {
+            if self.liked_songs_revision != ncm_client_guard.liked_songs_revision() {
+                self.liked_songs_revision = ncm_client_guard.liked_songs_revision();
+                self.liked_song_ids = ncm_client_guard.liked_song_ids().clone();
+            }
+        }
+        if let Some(current_song_id) = current_song_id {
+            self.is_song_liked = self.liked_song_ids.contains(&current_song_id);
+        }
 
         // bottom_bar 一直保持更新
         Ok(true)
@@ -87,14 +107,23 @@ impl<'a> Controller for BottomBar<'a> {
     }
 
     fn update_view(&mut self, style: &Style) {
-        self.control_bar = Paragraph::new(self.info_bar_text.clone()).block(Block::default().borders(Borders::ALL)).style(*style);
+        self.control_bar = Paragraph::new(self.info_bar_text.clone())
+            .block({
+                let mut block = Block::default().borders(Borders::ALL);
+                // 心动模式标识
+                if self.is_heart_mode {
+                    block = block.title_top(Line::from("\u{2665}心动模式").centered().fg(tailwind::PINK.c400));
+                }
+                block
+            })
+            .style(*style);
 
         self.playback_bar = Gauge::default()
             .block({
                 let mut block = Block::default().borders(Borders::ALL).style(*style);
                 if let (Some(song_name), Some(artist_name), Some(song_quality_level)) = (self.song_name.clone(), self.singer_name.clone(), self.song_quality_level.clone()) {
                     block = block
-                        .title_top(Line::from(format!("{}", song_name)).centered())
+                        .title_top(Line::from(format!("{} {}", if self.is_song_liked { '\u{2665}' } else { '\u{2661}' }, song_name)).centered())
                         .title_bottom(Line::from(format!("{}", artist_name)).centered())
                         .title_bottom(Line::from(format!("音质:{}", song_quality_level)).right_aligned());
                 }
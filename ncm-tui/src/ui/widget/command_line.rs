@@ -40,7 +40,7 @@ impl<'a> CommandLine<'a> {
             mode_label: Line::default(),
             colon_line: Line::default(),
             interactive_area: TextArea::default(),
-            tabs: Tabs::new(vec!["1.播放", "2.歌单", "0.help", "登录"])
+            tabs: Tabs::new(vec!["1.播放", "2.歌单", "3.搜索", "4.推荐", "5.排行", "6.电台", "7.最近", "8.我的", "0.help", "登录"])
                 .highlight_style(ITEM_SELECTED_STYLE)
                 .padding("", "")
                 .select(0)
@@ -106,8 +106,14 @@ impl<'a> Controller for CommandLine<'a> {
             Command::GotoScreen(to_screen) => match to_screen {
                 ScreenEnum::Main => self.tabs.to_owned().select(0),
                 ScreenEnum::Songlists => self.tabs.to_owned().select(1),
-                ScreenEnum::Help => self.tabs.to_owned().select(2),
-                ScreenEnum::Login => self.tabs.to_owned().select(3),
+                ScreenEnum::Search => self.tabs.to_owned().select(2),
+                ScreenEnum::Recommend => self.tabs.to_owned().select(3),
+                ScreenEnum::Toplists => self.tabs.to_owned().select(4),
+                ScreenEnum::Podcast => self.tabs.to_owned().select(5),
+                ScreenEnum::History => self.tabs.to_owned().select(6),
+                ScreenEnum::Profile => self.tabs.to_owned().select(7),
+                ScreenEnum::Help => self.tabs.to_owned().select(8),
+                ScreenEnum::Login => self.tabs.to_owned().select(9),
                 _ => self.tabs.to_owned().select(None),
             },
             _ => self.tabs.to_owned(),
@@ -145,7 +151,7 @@ impl<'a> Controller for CommandLine<'a> {
                 Constraint::Length(UnicodeWidthStr::width(self.current_mode.as_str()) as u16),
                 Constraint::Max(UnicodeWidthStr::width(if self.show_colon { ": " } else { "" }) as u16),
                 Constraint::Fill(1),
-                Constraint::Max(25),
+                Constraint::Max(32),
             ])
             .split(chunk);
 